@@ -30,15 +30,15 @@ fn main() {
     let mut humanoid = MorphologyBuilder::new("Torso");
     // Then you add appendages to it.
     // Each appendage has its own state and health value. More on that later.
-    humanoid.add_appendage("Torso", "Left Arm");
-    humanoid.add_appendage("Torso", "Right Arm");
-    humanoid.add_appendage("Left Arm", "Left Hand");
-    humanoid.add_appendage("Right Arm", "Right Hand");
-    humanoid.add_appendage("Torso", "Left Leg");
-    humanoid.add_appendage("Torso", "Right Leg");
-    humanoid.add_appendage("Left Leg", "Left Foot");
-    humanoid.add_appendage("Right Leg", "Right Foot");
-    humanoid.add_appendage("Torso", "Head");
+    humanoid.add_appendage("Torso", "Left Arm").unwrap();
+    humanoid.add_appendage("Torso", "Right Arm").unwrap();
+    humanoid.add_appendage("Left Arm", "Left Hand").unwrap();
+    humanoid.add_appendage("Right Arm", "Right Hand").unwrap();
+    humanoid.add_appendage("Torso", "Left Leg").unwrap();
+    humanoid.add_appendage("Torso", "Right Leg").unwrap();
+    humanoid.add_appendage("Left Leg", "Left Foot").unwrap();
+    humanoid.add_appendage("Right Leg", "Right Foot").unwrap();
+    humanoid.add_appendage("Torso", "Head").unwrap();
 
     let humanoid_structure = humanoid.build();
     println!("Here's our humanoid morphology:");
@@ -215,6 +215,7 @@ fn main() {
         map: Some(forest_map.id),
         x: 0,
         y: 4,
+        z: 0,
     };
     world.teleport_creature(alice, alice_spawn_point).unwrap();
 
@@ -236,16 +237,19 @@ fn main() {
         map: Some(forest_map.id),
         x: 0,
         y: 3,
+        z: 0,
     };
     let move_right = Position {
         map: Some(forest_map.id),
         x: 1,
         y: 3,
+        z: 0,
     };
     let move_diagonal = Position {
         map: Some(forest_map.id),
         x: 2,
         y: 2,
+        z: 0,
     };
     let movement_positions = vec![move_up, move_right, move_diagonal];
 
@@ -255,11 +259,13 @@ fn main() {
         map: Some(forest_map.id),
         x: 3,
         y: 1,
+        z: 0,
     };
     let diag_2 = Position {
         map: Some(forest_map.id),
         x: 4,
         y: 0,
+        z: 0,
     };
     let movement_positions = vec![diag_1, diag_2];
     world.move_creature(alice, movement_positions).unwrap();
@@ -271,16 +277,19 @@ fn main() {
         map: Some(forest_map.id),
         x: 3,
         y: 1,
+        z: 0,
     };
     let diag_2 = Position {
         map: Some(forest_map.id),
         x: 2,
         y: 2,
+        z: 0,
     };
     let diag_3 = Position {
         map: Some(forest_map.id),
         x: 1,
         y: 3,
+        z: 0,
     };
     let movement_positions = vec![diag_1, diag_2, diag_3];
     world.move_creature(alice, movement_positions).unwrap();
@@ -0,0 +1,143 @@
+use crate::creatures::components::CreatureActions;
+use crate::creatures::creature_builder::AppendageEffect;
+use crate::dice::components::DiceRoll;
+use crate::ecs::entity::Entity;
+use crate::ecs::resource::Resource;
+
+/// The ability an attack leans on for both hitting and damage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttackAbility {
+    /// Swords, clubs and fists.
+    #[default]
+    Strength,
+    /// Bows, daggers and thrown rocks.
+    Dexterity,
+}
+
+/// Luck on the attack roll. Advantage rolls two d20s and keeps the higher, disadvantage keeps the lower.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RollMode {
+    #[default]
+    Normal,
+    Advantage,
+    Disadvantage,
+}
+
+/// Where an attack is aimed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Targeting {
+    /// Lets the defender's hit-location table decide where the attack lands.
+    Random,
+    /// Aims at an appendage, by name or path. The smaller it is next to the rest of the body, the harder it is to hit.
+    Called(String),
+}
+
+/// One creature trying to hurt another. Resolve it with `World::resolve_attack`.
+#[derive(Debug, Clone)]
+pub struct Attack {
+    pub attacker: Entity,
+    pub defender: Entity,
+    /// Which appendage of the defender is aimed at.
+    pub target: Targeting,
+    pub effect: AppendageEffect,
+    /// Damage dice, e.g. `1d8`. The attacker's ability modifier is added on top.
+    pub damage: String,
+    pub ability: AttackAbility,
+    pub roll_mode: RollMode,
+    /// Bonus (or penalty) to the attack roll from weapons, cover, spells and the like.
+    pub to_hit_modifier: i32,
+    /// Bonus (or penalty) to the damage dealt.
+    pub damage_modifier: i32,
+}
+
+impl Attack {
+    pub fn new(attacker: Entity, defender: Entity, target: &str, damage: &str) -> Self {
+        Self {
+            attacker,
+            defender,
+            target: Targeting::Called(target.to_string()),
+            effect: AppendageEffect::Abrasion,
+            damage: damage.to_string(),
+            ability: AttackAbility::Strength,
+            roll_mode: RollMode::Normal,
+            to_hit_modifier: 0,
+            damage_modifier: 0,
+        }
+    }
+
+    /// An attack at no appendage in particular. Where it lands is rolled on the defender's hit-location table.
+    pub fn at_random(attacker: Entity, defender: Entity, damage: &str) -> Self {
+        Self {
+            target: Targeting::Random,
+            ..Self::new(attacker, defender, "", damage)
+        }
+    }
+
+    pub fn with_effect(mut self, effect: AppendageEffect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    pub fn with_ability(mut self, ability: AttackAbility) -> Self {
+        self.ability = ability;
+        self
+    }
+
+    pub fn with_roll_mode(mut self, roll_mode: RollMode) -> Self {
+        self.roll_mode = roll_mode;
+        self
+    }
+
+    pub fn with_modifiers(mut self, to_hit: i32, damage: i32) -> Self {
+        self.to_hit_modifier = to_hit;
+        self.damage_modifier = damage;
+        self
+    }
+}
+
+/// How an attack roll turned out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttackOutcome {
+    Hit,
+    /// A natural 20. Always hits and rolls the damage dice twice.
+    CriticalHit,
+    Miss,
+    /// A natural 1. Always misses.
+    CriticalMiss,
+}
+
+impl AttackOutcome {
+    pub fn is_hit(&self) -> bool {
+        matches!(self, AttackOutcome::Hit | AttackOutcome::CriticalHit)
+    }
+}
+
+/// Everything that went into resolving an attack.
+#[derive(Debug, Clone)]
+pub struct CombatLogEntry {
+    pub attacker: Entity,
+    pub defender: Entity,
+    /// The appendage aimed at, or the one a random attack landed on.
+    pub target: String,
+    /// Taken off the attack roll for aiming at the target. 0 for random attacks.
+    pub called_shot_penalty: i32,
+    /// The d20 roll, before modifiers.
+    pub attack_roll: DiceRoll,
+    /// The attack roll with every modifier added.
+    pub attack_total: i32,
+    /// What the attack had to meet or beat.
+    pub defense: i32,
+    pub outcome: AttackOutcome,
+    /// The damage roll, before modifiers. None on a miss.
+    pub damage_roll: Option<DiceRoll>,
+    /// The action to apply to the defender. None on a miss.
+    pub action: Option<CreatureActions>,
+}
+
+/// Every attack resolved in the world, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct CombatLog {
+    pub entries: Vec<CombatLogEntry>,
+}
+
+impl Resource for CombatLog {}
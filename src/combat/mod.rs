@@ -0,0 +1,4 @@
+/// Attack resolution. Attacks are rolled against the defender's defense using both creatures' sheets,
+/// and every attack lands in the combat log.
+pub mod components;
+pub(crate) mod systems;
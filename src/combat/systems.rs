@@ -0,0 +1,196 @@
+use crate::combat::components::{
+    Attack, AttackAbility, AttackOutcome, CombatLog, CombatLogEntry, RollMode, Targeting,
+};
+use crate::creatures::components::{CreatureActions, CreatureSheet};
+use crate::creatures::creature_builder::Appendage;
+use crate::creatures::Creature;
+use crate::dice::expression::{DiceExpression, DiceTerm, Keep};
+use crate::ecs::entity::Entity;
+use crate::ecs::world::World;
+use crate::errors::{SimutronError, SimutronResult};
+use crate::runtime_error;
+use log::debug;
+
+/// Called shots lose this much to hit for every time the target's hit weight halves next to the heaviest appendage.
+const CALLED_SHOT_PENALTY_PER_HALVING: i32 = 2;
+
+impl World {
+    /// Rolls an attack against the defender's defense and, on a hit, rolls damage.
+    /// The result is written to the combat log. Nothing is applied to the defender; see `perform_attack`.
+    ///
+    /// The attack roll is a d20 plus the attacker's ability modifier and any to-hit modifier, less the penalty
+    /// for a called shot. It hits if it meets or beats 10 plus the defender's dexterity modifier.
+    /// Random attacks roll where they land on the defender's hit-location table first.
+    pub fn resolve_attack(&mut self, attack: &Attack) -> SimutronResult<CombatLogEntry> {
+        let attacker = self.get_sheet(attack.attacker)?;
+        let defender = self.get_sheet(attack.defender)?;
+        let (target, called_shot_penalty) = match &attack.target {
+            Targeting::Random => (self.roll_hit_location(attack.defender)?, 0),
+            Targeting::Called(target) => self.get_called_shot(attack.defender, target)?,
+        };
+        let ability = CreatureSheet::get_modifier(match attack.ability {
+            AttackAbility::Strength => attacker.strength,
+            AttackAbility::Dexterity => attacker.dexterity,
+        });
+        let defense = 10 + CreatureSheet::get_modifier(defender.dexterity);
+        let mut damage = DiceExpression::parse(&attack.damage)?;
+
+        let attack_roll = self.roll(match attack.roll_mode {
+            RollMode::Normal => "1d20",
+            RollMode::Advantage => "1d20adv",
+            RollMode::Disadvantage => "1d20dis",
+        })?;
+        let attack_total = attack_roll
+            .total
+            .saturating_add(ability)
+            .saturating_add(attack.to_hit_modifier)
+            .saturating_sub(called_shot_penalty);
+        let outcome = if attack_roll.is_max() {
+            AttackOutcome::CriticalHit
+        } else if attack_roll.is_min() {
+            AttackOutcome::CriticalMiss
+        } else if attack_total >= defense {
+            AttackOutcome::Hit
+        } else {
+            AttackOutcome::Miss
+        };
+
+        let mut entry = CombatLogEntry {
+            attacker: attack.attacker,
+            defender: attack.defender,
+            target: target.clone(),
+            called_shot_penalty,
+            attack_roll,
+            attack_total,
+            defense,
+            outcome,
+            damage_roll: None,
+            action: None,
+        };
+        if outcome.is_hit() {
+            if outcome == AttackOutcome::CriticalHit {
+                Self::double_dice(&mut damage);
+            }
+            let damage_roll = self.with_dice(|dice| dice.roll_expression(&damage))?;
+            // A hit always hurts a little, however weak the attacker.
+            let total = damage_roll
+                .total
+                .saturating_add(ability)
+                .saturating_add(attack.damage_modifier)
+                .max(1);
+            entry.action = Some(CreatureActions {
+                from: attack.attacker,
+                to: attack.defender,
+                target,
+                effect: attack.effect,
+                impact: total.saturating_neg(),
+            });
+            entry.damage_roll = Some(damage_roll);
+        }
+        debug!(
+            "{:?} attacks {:?}: {} against {}, {:?}",
+            attack.attacker, attack.defender, attack_total, defense, outcome
+        );
+
+        if self.get_resource::<CombatLog>().is_none() {
+            self.insert_resource(CombatLog::default());
+        }
+        if let Some(log) = self.get_resource_mut::<CombatLog>() {
+            log.entries.push(entry.clone());
+        }
+        Ok(entry)
+    }
+
+    /// Resolves an attack and applies the damage to the defender on a hit.
+    pub fn perform_attack(&mut self, attack: &Attack) -> SimutronResult<CombatLogEntry> {
+        let entry = self.resolve_attack(attack)?;
+        if let Some(action) = &entry.action {
+            self.apply_creature_action(action)?;
+        }
+        Ok(entry)
+    }
+
+    /// Rolls where an untargeted attack lands on a creature, weighted by its hit-location table.
+    /// Returns the name of the appendage hit.
+    pub fn roll_hit_location(&mut self, creature: Entity) -> SimutronResult<String> {
+        let total = match self.get_component::<Creature>(creature) {
+            Some(body) => match body
+                .corpus
+                .get_hit_locations()
+                .iter()
+                .try_fold(0u32, |total, (_, weight)| total.checked_add(*weight))
+            {
+                Some(0) => return runtime_error!("{} has nothing to hit.", body.name),
+                Some(total) => total,
+                None => return runtime_error!("{}'s hit weights add up to too much.", body.name),
+            },
+            None => return runtime_error!("Entity {:?} is not a creature.", creature),
+        };
+        let roll = self.with_dice(|dice| dice.roll_die(total))? - 1;
+        let body = match self.get_component::<Creature>(creature) {
+            Some(body) => body,
+            None => return runtime_error!("Entity {:?} is not a creature.", creature),
+        };
+        match body.corpus.get_hit_location(roll) {
+            Some(appendage) => Ok(appendage.get_name().to_string()),
+            None => runtime_error!("{} has nothing to hit.", body.name),
+        }
+    }
+
+    /// Returns the name of the appendage a called shot aims at, and the penalty to hit it.
+    fn get_called_shot(&self, creature: Entity, target: &str) -> SimutronResult<(String, i32)> {
+        let body = match self.get_component::<Creature>(creature) {
+            Some(body) => body,
+            None => return runtime_error!("Entity {:?} is not a creature.", creature),
+        };
+        match body.find(target) {
+            Some(appendage) => Ok((
+                appendage.get_name().to_string(),
+                Self::get_called_shot_penalty(&body.corpus, appendage),
+            )),
+            None => runtime_error!("{} has no {}.", body.name, target),
+        }
+    }
+
+    /// Aiming at the heaviest appendage in the hit-location table is free. Every halving of the weight after that
+    /// costs `CALLED_SHOT_PENALTY_PER_HALVING`, and appendages that are never hit at random are hardest of all.
+    fn get_called_shot_penalty(corpus: &Appendage, target: &Appendage) -> i32 {
+        let heaviest = corpus
+            .get_hit_locations()
+            .iter()
+            .map(|(_, weight)| *weight)
+            .max()
+            .unwrap_or(0);
+        let mut weight = target.get_spec().hit_weight.max(1);
+        let mut halvings = 0;
+        while weight.saturating_mul(2) <= heaviest {
+            weight *= 2;
+            halvings += 1;
+        }
+        halvings * CALLED_SHOT_PENALTY_PER_HALVING
+    }
+
+    fn get_sheet(&self, creature: Entity) -> SimutronResult<CreatureSheet> {
+        if self.get_component::<Creature>(creature).is_none() {
+            return runtime_error!("Entity {:?} is not a creature.", creature);
+        }
+        match self.get_component::<CreatureSheet>(creature) {
+            Some(sheet) => Ok(sheet.clone()),
+            None => runtime_error!("Creature has no Creature Sheet component."),
+        }
+    }
+
+    /// A critical hit rolls every damage die twice. Flat modifiers are not doubled.
+    fn double_dice(expression: &mut DiceExpression) {
+        for term in expression.terms.iter_mut() {
+            if let DiceTerm::Dice { count, keep, .. } = term {
+                *count *= 2;
+                *keep = match keep {
+                    Keep::All => Keep::All,
+                    Keep::Highest(k) => Keep::Highest(*k * 2),
+                    Keep::Lowest(k) => Keep::Lowest(*k * 2),
+                };
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
-use crate::creatures::creature_builder::AppendageEffect;
+use crate::creatures::creature_builder::{Appendage, AppendageEffect, Health};
+use crate::creatures::Creature;
 use crate::ecs::component::Component;
 use crate::ecs::entity::Entity;
+use crate::ecs::resource::Resource;
+use crate::map::base_terrain::MovementMode;
+use std::collections::HashMap;
 
 /// The stats that define a creature's capabilities.
 #[derive(Clone, Debug)]
@@ -14,6 +18,137 @@ pub struct CreatureSheet {
     pub charisma: u8,
 }
 impl Component for CreatureSheet {}
+impl CreatureSheet {
+    /// Returns the bonus an ability score adds to rolls. 10 and 11 add nothing.
+    pub fn get_modifier(score: u8) -> i32 {
+        (score as i32 - 10).div_euclid(2)
+    }
+}
+
+/// Speeds for the ways a creature can move besides walking.
+/// A creature walks at its `CreatureSheet::speed` unless a walking speed is given here.
+/// `move_creature` picks the cheapest mode for every step of a path.
+#[derive(Clone, Debug, Default)]
+pub struct MovementModes {
+    pub speeds: HashMap<MovementMode, u32>,
+}
+impl Component for MovementModes {}
+impl MovementModes {
+    pub fn new() -> Self {
+        Self {
+            speeds: HashMap::new(),
+        }
+    }
+
+    pub fn with_speed(mut self, mode: MovementMode, speed: u32) -> Self {
+        self.speeds.insert(mode, speed);
+        self
+    }
+}
+
+/// Tracks how much movement a creature has used this turn.
+/// `move_creature` spends from it across calls, so a creature cannot walk further than its speed in one turn.
+/// Only what was spent is stored: the movement left is worked out from the creature's current speed,
+/// so a haste or a slow mid-turn takes effect straight away.
+/// The budget is cleared with `World::reset_movement_budget` at the start of every turn.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MovementBudget {
+    /// Movement already used this turn.
+    pub spent: u32,
+    /// How many times the creature dashed this turn.
+    pub dashes: u32,
+}
+impl Component for MovementBudget {}
+impl MovementBudget {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clears the budget for a new turn.
+    pub fn reset(&mut self) {
+        self.spent = 0;
+        self.dashes = 0;
+    }
+
+    /// Spends the turn's action on moving again, granting another full speed worth of movement.
+    pub fn dash(&mut self) {
+        self.dashes += 1;
+    }
+
+    /// Spends movement from the budget.
+    pub fn spend(&mut self, amount: u32) {
+        self.spent = self.spent.saturating_add(amount);
+    }
+
+    /// The movement still available this turn for a creature moving at `speed`. Never goes below zero.
+    pub fn get_remaining(&self, speed: u32) -> u32 {
+        speed
+            .saturating_mul(self.dashes.saturating_add(1))
+            .saturating_sub(self.spent)
+    }
+}
+
+/// A body part lying around after being cut off a creature. Lives on the prop spawned for it.
+/// A healer can sew it back on with `World::reattach_appendage`.
+#[derive(Clone, Debug)]
+pub struct SeveredAppendage {
+    /// The appendage, along with everything that was connected to it.
+    pub appendage: Appendage,
+    /// The creature it was cut from.
+    pub owner: Entity,
+    /// The name of the appendage it was attached to.
+    pub attached_to: String,
+}
+impl Component for SeveredAppendage {}
+
+/// How alive a creature is. States are ordered from best to worst.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum VitalState {
+    #[default]
+    Alive,
+    /// Knocked out. Cannot act, but is in no danger.
+    Unconscious,
+    /// Cannot act and loses health every tick until healed or dead.
+    Dying,
+    Dead,
+}
+
+/// The last vital state the world saw for a creature, used to spot transitions.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Vitals {
+    pub state: VitalState,
+}
+impl Component for Vitals {}
+
+/// Emitted whenever a creature's vital state changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VitalStateChanged {
+    pub creature: Entity,
+    pub previous: VitalState,
+    pub current: VitalState,
+}
+
+/// Vital state changes waiting to be handled, oldest first. Take them with `World::drain_vital_events`.
+#[derive(Clone, Debug, Default)]
+pub struct VitalEvents {
+    pub events: Vec<VitalStateChanged>,
+}
+impl Resource for VitalEvents {}
+
+/// What happens to the dead. Insert it as a resource to change it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DeathRules {
+    /// Replace dead creatures with a corpse prop holding their inventory.
+    pub leave_corpses: bool,
+}
+impl Resource for DeathRules {}
+
+/// The remains of a creature. Lives on the corpse prop.
+#[derive(Clone, Debug)]
+pub struct Corpse {
+    pub creature: Creature,
+}
+impl Component for Corpse {}
 
 /// Represents an action taken by a character on another character
 /// A traveler object.
@@ -28,7 +163,7 @@ pub struct CreatureActions {
     /// What effect the action has on the target appendage.
     pub effect: AppendageEffect,
     /// The health impact of the action on the target appendage.Positive values heal, negative values damage.
-    pub impact: i8,
+    pub impact: Health,
 }
 
 // impl Component for CreatureActions {}
@@ -1,8 +1,55 @@
-use std::collections::HashMap;
+use crate::ecs::entity::Entity;
+use crate::equipment::components::EquipmentSlot;
+use crate::errors::{SimutronError, SimutronResult};
+use crate::runtime_error;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet, VecDeque};
 
-/// Describes the state of an appendage.
-#[derive(Clone, Default, Copy, Debug, Eq, Hash, PartialEq)]
-enum AppendageHealth {
+/// Health, damage and healing. Every sum saturates, so no amount of hits can overflow.
+pub type Health = i32;
+
+/// Max health of an unharmed appendage in a morphology that does not set its own scale.
+pub const DEFAULT_HEALTH_SCALE: Health = 100;
+/// A crushing blow that has taken this share of an appendage's max health leaves it disabled,
+/// however healthy it is otherwise.
+const CRUSH_DISABLES_AT_PERCENT: Health = 30;
+/// Share of its max health the torso loses every tick for each appendage carrying untreated poison.
+const POISON_SPREAD_PERCENT: Health = 2;
+/// Share of its max health an untreated infection takes from its appendage every tick.
+const INFECTION_GROWTH_PERCENT: Health = 2;
+
+/// How likely an appendage that does not set its own weight is to be hit by an untargeted attack.
+pub const DEFAULT_HIT_WEIGHT: u32 = 10;
+
+/// How many entries an appendage's timeline keeps before dropping the oldest.
+pub const TIMELINE_LENGTH: usize = 64;
+/// Most appendages a chain from the root of a morphology can pass through, root included.
+/// Appendages are walked recursively, so much deeper bodies risk running out of stack.
+pub const MAX_MORPHOLOGY_DEPTH: usize = 256;
+
+/// Separates appendage names in a path, e.g. `Torso/Left Arm/Left Hand`.
+pub const PATH_SEPARATOR: char = '/';
+
+/// Takes armor off damage from cuts, crushing and burns. Poison, infections and healing get through untouched.
+pub(crate) fn apply_armor(effect: AppendageEffect, impact: Health, armor: Health) -> Health {
+    match effect {
+        AppendageEffect::Abrasion | AppendageEffect::Crush | AppendageEffect::Burn
+            if impact < 0 =>
+        {
+            impact.saturating_add(armor.max(0)).min(0)
+        }
+        _ => impact,
+    }
+}
+
+/// Returns `percent` percent of `amount`, without overflowing.
+pub(crate) fn percent_of(amount: Health, percent: Health) -> Health {
+    (amount as i64 * percent as i64 / 100).clamp(Health::MIN as i64, Health::MAX as i64) as Health
+}
+
+/// Describes the state of an appendage. States are ordered from healthiest to worst.
+#[derive(Clone, Default, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum AppendageHealth {
     /// Full health of the appendage with no impairments.
     #[default]
     Full,
@@ -14,72 +61,816 @@ enum AppendageHealth {
     Amputated,
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
 pub enum AppendageEffect {
+    /// Cuts, scrapes and bruises. Plain damage that heals normally.
     Abrasion,
-    // Crush,
-    // Burn,
-    // Poison,
-    // Infect,
+    /// Blunt trauma. Enough of it disables the appendage no matter its remaining health.
+    Crush,
+    /// Burns heal at half the rate of other wounds.
+    Burn,
+    /// Untreated poison in a limb keeps seeping into the torso every tick.
+    Poison,
+    /// Untreated infections worsen every tick.
+    Infect,
+}
+
+/// What an appendage does for its creature. A capability only counts while the appendage is working,
+/// that is while it is no worse than wounded.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Capability {
+    /// Holds and uses items. A creature with no working grasping appendage cannot use items.
+    Grasp,
+    /// Carries the creature around. Speed shrinks with every locomotion appendage lost.
+    Locomotion,
+    Sight,
+    Speech,
+    /// The creature dies without it.
+    Vital,
+}
+
+/// How damage travels through a morphology. Set per morphology with `MorphologyBuilder::set_cascade_rules`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CascadeRules {
+    /// An amputated appendage amputates everything connected to it too. Without this, whatever is connected
+    /// still leaves with it (no hand without an arm), but keeps its own state.
+    pub sever_children: bool,
+    /// A disabled appendage disables everything connected to it (a broken arm cannot work its hand).
+    pub disable_children: bool,
+    /// Percentage of the damage dealt to an appendage that is also dealt to the appendage it is connected to.
+    pub bubble_up_percent: u8,
+}
+
+impl Default for CascadeRules {
+    fn default() -> Self {
+        CascadeRules {
+            sever_children: true,
+            disable_children: true,
+            bubble_up_percent: 0,
+        }
+    }
+}
+
+impl CascadeRules {
+    /// Returns the share of `impact` passed on to the parent. Healing never bubbles up.
+    fn get_bubbled(&self, impact: Health) -> Health {
+        if impact >= 0 {
+            return 0;
+        }
+        percent_of(impact, self.bubble_up_percent.min(100) as Health)
+    }
+}
+
+/// One entry in an appendage's timeline.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct EffectRecord {
+    /// The world tick the effect landed on.
+    pub tick: u64,
+    /// Who caused it. None for wounds progressing on their own.
+    pub source: Option<Entity>,
+    pub effect: AppendageEffect,
+    /// The change in health it caused, after armor, resistances and clamping.
+    pub impact: Health,
+    /// The appendage's state right after.
+    pub state: AppendageHealth,
+}
+
+/// When an effect happens and who is behind it.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct EffectCause {
+    pub(crate) tick: u64,
+    pub(crate) source: Option<Entity>,
+}
+
+/// The lowest health, in percent of an appendage's maximum, that still counts as each state.
+/// Anything below `disabled` is amputated.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct HealthThresholds {
+    pub full: u8,
+    pub wounded: u8,
+    pub disabled: u8,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        HealthThresholds {
+            full: 99,
+            wounded: 30,
+            // The idea is that once an appendage is too "damaged", it cannot be healed back to functionality.
+            disabled: 16,
+        }
+    }
+}
+
+/// Describes a single appendage for `MorphologyBuilder`. A plain name gets the default spec.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AppendageSpec {
+    pub name: String,
+    /// Health of the appendage when unharmed, on a scale where a default appendage has 100.
+    /// The morphology's health scale multiplies it when the morphology is built.
+    pub max_health: Health,
+    pub thresholds: HealthThresholds,
+    /// Damage soaked up by every cut, crush and burn before it reaches the appendage.
+    pub armor: Health,
+    /// Percentage of the damage from an effect the appendage shrugs off, after armor.
+    /// Negative values are vulnerabilities, so -50 makes the effect hurt half again as much.
+    pub resistances: HashMap<AppendageEffect, i8>,
+    /// How likely an untargeted attack is to land on the appendage, relative to the rest of the body.
+    /// An appendage with no weight is never hit at random.
+    pub hit_weight: u32,
+    /// Where equipment can be worn or wielded on the appendage.
+    pub slots: HashSet<EquipmentSlot>,
+}
+
+impl Default for AppendageSpec {
+    fn default() -> Self {
+        AppendageSpec {
+            name: String::new(),
+            max_health: DEFAULT_HEALTH_SCALE,
+            thresholds: HealthThresholds::default(),
+            armor: 0,
+            resistances: HashMap::new(),
+            hit_weight: DEFAULT_HIT_WEIGHT,
+            slots: HashSet::new(),
+        }
+    }
+}
+
+impl From<&str> for AppendageSpec {
+    fn from(name: &str) -> Self {
+        AppendageSpec::new(name)
+    }
 }
 
+impl AppendageSpec {
+    pub fn new(name: &str) -> Self {
+        AppendageSpec {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_max_health(mut self, max_health: Health) -> Self {
+        self.max_health = max_health.max(1);
+        self
+    }
+
+    pub fn with_thresholds(mut self, thresholds: HealthThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn with_armor(mut self, armor: Health) -> Self {
+        self.armor = armor.max(0);
+        self
+    }
+
+    /// Sets the percentage of damage from an effect that is ignored. Use a negative percentage for a vulnerability.
+    pub fn with_resistance(mut self, effect: AppendageEffect, percent: i8) -> Self {
+        self.resistances.insert(effect, percent.clamp(-100, 100));
+        self
+    }
+
+    pub fn with_hit_weight(mut self, hit_weight: u32) -> Self {
+        self.hit_weight = hit_weight;
+        self
+    }
+
+    pub fn with_slots(mut self, slots: &[EquipmentSlot]) -> Self {
+        self.slots.extend(slots.iter().copied());
+        self
+    }
+
+    /// Returns how much of the damage dealt by an effect gets through armor and resistances. Healing is untouched.
+    fn mitigate(&self, effect: AppendageEffect, impact: Health) -> Health {
+        if impact >= 0 {
+            return impact;
+        }
+        let armored = apply_armor(effect, impact, self.armor);
+        let resistance = self.resistances.get(&effect).copied().unwrap_or(0) as Health;
+        percent_of(armored, 100 - resistance).min(0)
+    }
+}
+
+/// Identifies an appendage within its morphology. Handed out by `MorphologyBuilder` as appendages are added,
+/// and kept through renames, moves, severing and reattaching to the same body.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AppendageId(pub u32);
+
 /// An appendage represents an external body part of a character. Characters can have multiple appendages, each with their own health and state.
 /// More humanoid characters will have standard appendages like arms and legs, while more exotic characters may have unique appendages like tails or wings.
 /// Each appendage tracks its own health, state, and history of effects that have impacted it.
-#[derive(Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Appendage {
+    pub(crate) id: AppendageId,
     /// The name of the appendage (e.g., "Left Arm", "Right Leg")
     pub(crate) name: String,
+    /// Max health, thresholds, armor and resistances the appendage was built with.
+    spec: AppendageSpec,
     /// The state of the appendage based on health.
     state: AppendageHealth,
     /// A log of significant events affecting the appendage.
     /// Stored as a tuple of Effect and the health impact it had on the appendage.
-    effect_history: HashMap<AppendageEffect, i8>,
+    effect_history: HashMap<AppendageEffect, Health>,
+    /// The last effects to land on the appendage, oldest first. Holds at most `TIMELINE_LENGTH` entries.
+    timeline: VecDeque<EffectRecord>,
     /// An appendage is connected to something else ( Torso -> Head, leg -> foot, Arm -> Hand )
     pub(crate) connected_to: Option<Vec<Appendage>>,
+    /// Poisons and infections that were treated and no longer progress.
+    treated: HashSet<AppendageEffect>,
+    /// The worst state forced on the appendage by whatever it is connected to.
+    cascaded: AppendageHealth,
+    /// How damage travels through the morphology. Only the root's rules are used.
+    cascade: CascadeRules,
+    /// What the appendage does for its creature.
+    capabilities: HashSet<Capability>,
+    /// How many appendages of the whole morphology had each capability when it was built.
+    /// Only kept on the root, so lost appendages are still missed.
+    base_capabilities: HashMap<Capability, u32>,
+}
+
+impl Default for Appendage {
+    fn default() -> Self {
+        Appendage::new(AppendageSpec::default())
+    }
 }
 
 impl Appendage {
-    fn new(name: &str) -> Self {
+    fn new(spec: AppendageSpec) -> Self {
         Appendage {
-            name: name.to_string(),
+            id: AppendageId::default(),
+            name: spec.name.clone(),
+            spec,
             state: AppendageHealth::Full,
             effect_history: HashMap::new(),
+            timeline: VecDeque::new(),
             connected_to: None,
+            treated: HashSet::new(),
+            cascaded: AppendageHealth::Full,
+            cascade: CascadeRules::default(),
+            capabilities: HashSet::new(),
+            base_capabilities: HashMap::new(),
+        }
+    }
+
+    pub fn get_id(&self) -> AppendageId {
+        self.id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_state(&self) -> AppendageHealth {
+        self.state
+    }
+
+    pub fn get_spec(&self) -> &AppendageSpec {
+        &self.spec
+    }
+
+    pub fn get_cascade_rules(&self) -> CascadeRules {
+        self.cascade
+    }
+
+    pub fn has_slot(&self, slot: EquipmentSlot) -> bool {
+        self.spec.slots.contains(&slot)
+    }
+
+    pub fn has_capability(&self, capability: Capability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+
+    /// Returns true if the appendage is still of any use, i.e. no worse than wounded.
+    pub fn is_working(&self) -> bool {
+        self.state <= AppendageHealth::Wounded
+    }
+
+    /// Counts the working appendages with a capability in this appendage and everything connected to it.
+    pub fn count_working(&self, capability: Capability) -> u32 {
+        let own = (self.is_working() && self.has_capability(capability)) as u32;
+        own + self
+            .connected_to
+            .iter()
+            .flatten()
+            .map(|child| child.count_working(capability))
+            .sum::<u32>()
+    }
+
+    /// Returns the working appendage with a capability that has the least health left.
+    pub fn find_weakest(&self, capability: Capability) -> Option<&Appendage> {
+        let own = (self.is_working() && self.has_capability(capability)).then_some(self);
+        self.connected_to
+            .iter()
+            .flatten()
+            .filter_map(|child| child.find_weakest(capability))
+            .chain(own)
+            .min_by_key(|appendage| appendage.get_health_percent())
+    }
+
+    /// Returns how many appendages had a capability when the morphology was built.
+    pub fn get_base_capability(&self, capability: Capability) -> u32 {
+        self.base_capabilities
+            .get(&capability)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn scale_health(&mut self, scale: Health) {
+        let max_health = self.spec.max_health as i64 * scale as i64 / DEFAULT_HEALTH_SCALE as i64;
+        self.spec.max_health = max_health.clamp(1, Health::MAX as i64) as Health;
+        for child in self.connected_to.iter_mut().flatten() {
+            child.scale_health(scale);
+        }
+    }
+
+    fn get_depth(&self) -> usize {
+        let mut deepest = 0;
+        let mut pending = vec![(self, 1)];
+        while let Some((appendage, depth)) = pending.pop() {
+            deepest = deepest.max(depth);
+            pending.extend(
+                appendage
+                    .connected_to
+                    .iter()
+                    .flatten()
+                    .map(|child| (child, depth + 1)),
+            );
+        }
+        deepest
+    }
+
+    fn count_capabilities(&self, counts: &mut HashMap<Capability, u32>) {
+        for capability in &self.capabilities {
+            *counts.entry(*capability).or_insert(0) += 1;
+        }
+        for child in self.connected_to.iter().flatten() {
+            child.count_capabilities(counts);
+        }
+    }
+
+    pub fn get_health(&self) -> Health {
+        self.effect_history
+            .values()
+            .fold(self.spec.max_health, |health, impact| {
+                health.saturating_add(*impact)
+            })
+    }
+
+    pub fn get_max_health(&self) -> Health {
+        self.spec.max_health
+    }
+
+    /// Returns the health left as a percentage of the appendage's maximum, never below 0.
+    pub fn get_health_percent(&self) -> u8 {
+        let max_health = self.spec.max_health.max(1) as i64;
+        (self.get_health().max(0) as i64 * 100 / max_health).min(100) as u8
+    }
+
+    /// Returns a share of the appendage's max health, at least 1.
+    pub(crate) fn get_share_of_max(&self, percent: Health) -> Health {
+        percent_of(self.spec.max_health, percent).max(1)
+    }
+
+    /// Returns the health an effect has taken off the appendage so far.
+    pub fn get_effect_impact(&self, effect: AppendageEffect) -> Health {
+        self.effect_history.get(&effect).copied().unwrap_or(0)
+    }
+
+    /// Returns the effects that landed on the appendage, oldest first.
+    pub fn get_timeline(&self) -> impl Iterator<Item = &EffectRecord> {
+        self.timeline.iter()
+    }
+
+    /// Returns the effects that landed on the appendage on or after a tick, oldest first.
+    pub fn get_timeline_since(&self, tick: u64) -> impl Iterator<Item = &EffectRecord> {
+        self.timeline
+            .iter()
+            .filter(move |record| record.tick >= tick)
+    }
+
+    /// Returns the effects an entity caused on the appendage, oldest first.
+    pub fn get_timeline_from(&self, source: Entity) -> impl Iterator<Item = &EffectRecord> {
+        self.timeline
+            .iter()
+            .filter(move |record| record.source == Some(source))
+    }
+
+    fn record(&mut self, effect: AppendageEffect, impact: Health, cause: EffectCause) {
+        if self.timeline.len() >= TIMELINE_LENGTH {
+            self.timeline.pop_front();
+        }
+        self.timeline.push_back(EffectRecord {
+            tick: cause.tick,
+            source: cause.source,
+            effect,
+            impact,
+            state: self.state,
+        });
+    }
+
+    /// Returns true if a poison or infection in the appendage is still progressing.
+    pub fn is_untreated(&self, effect: AppendageEffect) -> bool {
+        self.get_effect_impact(effect) < 0 && !self.treated.contains(&effect)
+    }
+
+    /// Looks for an appendage in this appendage and everything connected to it. Takes a name, or a path
+    /// of names starting from this appendage such as `Torso/Left Arm/Left Hand`.
+    pub fn find(&self, target: &str) -> Option<&Appendage> {
+        if target.contains(PATH_SEPARATOR) {
+            let mut names = target.split(PATH_SEPARATOR);
+            if names.next()? != self.name {
+                return None;
+            }
+            return names.try_fold(self, |current, name| {
+                current
+                    .connected_to
+                    .iter()
+                    .flatten()
+                    .find(|child| child.name == name)
+            });
+        }
+        if self.name == target {
+            return Some(self);
+        }
+        self.connected_to
+            .iter()
+            .flatten()
+            .find_map(|child| child.find(target))
+    }
+
+    pub fn find_by_id(&self, id: AppendageId) -> Option<&Appendage> {
+        self.iter().find(|appendage| appendage.id == id)
+    }
+
+    /// Returns this appendage and everything connected to it, parents first.
+    pub fn iter(&self) -> impl Iterator<Item = &Appendage> {
+        let mut appendages = Vec::new();
+        let mut pending = vec![self];
+        while let Some(appendage) = pending.pop() {
+            appendages.push(appendage);
+            if let Some(children) = &appendage.connected_to {
+                pending.extend(children.iter().rev());
+            }
+        }
+        appendages.into_iter()
+    }
+
+    /// Returns every appendage an untargeted attack can land on, with its hit weight, parents first.
+    pub fn get_hit_locations(&self) -> Vec<(&Appendage, u32)> {
+        self.iter()
+            .filter(|appendage| {
+                appendage.spec.hit_weight > 0 && appendage.state != AppendageHealth::Amputated
+            })
+            .map(|appendage| (appendage, appendage.spec.hit_weight))
+            .collect()
+    }
+
+    /// Picks the appendage at `roll` in the hit-location table, where `roll` runs from 0 to the total weight.
+    /// Returns None once the roll is past the end of the table.
+    pub fn get_hit_location(&self, roll: u32) -> Option<&Appendage> {
+        let mut remaining = roll;
+        for (appendage, weight) in self.get_hit_locations() {
+            if remaining < weight {
+                return Some(appendage);
+            }
+            remaining -= weight;
+        }
+        None
+    }
+
+    /// Returns the appendage the target is connected to. The appendage this is called on has no parent.
+    pub fn parent_of(&self, target: &str) -> Option<&Appendage> {
+        let id = self.find(target)?.id;
+        self.iter().find(|appendage| {
+            appendage
+                .connected_to
+                .iter()
+                .flatten()
+                .any(|child| child.id == id)
+        })
+    }
+
+    /// Returns the path from this appendage to the target, e.g. `Torso/Left Arm/Left Hand`.
+    pub fn get_path(&self, target: &str) -> Option<String> {
+        let mut names = vec![self.find(target)?.name.as_str()];
+        let mut current = target.to_string();
+        while let Some(parent) = self.parent_of(&current) {
+            names.push(parent.name.as_str());
+            current = parent.name.clone();
+        }
+        names.reverse();
+        Some(names.join(&PATH_SEPARATOR.to_string()))
+    }
+
+    /// Returns the names of this appendage and everything connected to it, parents first.
+    pub(crate) fn get_names(&self) -> Vec<String> {
+        let mut names = vec![self.name.clone()];
+        for child in self.connected_to.iter().flatten() {
+            names.extend(child.get_names());
+        }
+        names
+    }
+
+    pub(crate) fn find_mut(&mut self, target: &str) -> Option<&mut Appendage> {
+        if target.contains(PATH_SEPARATOR) {
+            let mut names = target.split(PATH_SEPARATOR);
+            if names.next()? != self.name {
+                return None;
+            }
+            return names.try_fold(self, |current, name| {
+                current
+                    .connected_to
+                    .iter_mut()
+                    .flatten()
+                    .find(|child| child.name == name)
+            });
+        }
+        if self.name == target {
+            return Some(self);
+        }
+        self.connected_to
+            .iter_mut()
+            .flatten()
+            .find_map(|child| child.find_mut(target))
+    }
+
+    pub(crate) fn find_by_id_mut(&mut self, id: AppendageId) -> Option<&mut Appendage> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.connected_to
+            .iter_mut()
+            .flatten()
+            .find_map(|child| child.find_by_id_mut(id))
+    }
+
+    /// Cuts every amputated appendage out of this body.
+    /// Each comes back along with the name of the appendage it was attached to.
+    pub(crate) fn detach_amputated(&mut self) -> Vec<(String, Appendage)> {
+        let mut severed = Vec::new();
+        let name = self.name.clone();
+        if let Some(children) = self.connected_to.as_mut() {
+            let (lost, kept): (Vec<Appendage>, Vec<Appendage>) = children
+                .drain(..)
+                .partition(|child| child.state == AppendageHealth::Amputated);
+            *children = kept;
+            // Whatever hangs off a lost appendage goes with it, even if the cascade rules spared it.
+            for child in lost {
+                severed.push((name.clone(), child));
+            }
+            for child in children.iter_mut() {
+                severed.extend(child.detach_amputated());
+            }
+        }
+        if self
+            .connected_to
+            .as_ref()
+            .is_some_and(|children| children.is_empty())
+        {
+            self.connected_to = None;
+        }
+        severed
+    }
+
+    /// Connects a severed appendage to the named appendage in this body.
+    /// The appendage comes back wounded and whatever hung off it works again.
+    /// Returns false if there is nothing by that name to attach it to.
+    pub(crate) fn attach(&mut self, parent: &str, mut appendage: Appendage) -> bool {
+        // Ids are only unique within one morphology, so a part from another body is numbered after this one's.
+        let taken: HashSet<AppendageId> = self.iter().map(|part| part.id).collect();
+        if appendage.iter().any(|part| taken.contains(&part.id)) {
+            let mut last = taken.iter().map(|id| id.0).max().unwrap_or(0);
+            appendage.renumber(&mut last);
+        }
+        let parent = match self.find_mut(parent) {
+            Some(parent) if parent.state != AppendageHealth::Amputated => parent,
+            _ => return false,
+        };
+        // Reattached appendages come back with at least enough health to count as wounded rather than lost.
+        let wounded = appendage.spec.thresholds.wounded;
+        if appendage.get_health_percent() < wounded {
+            let max_health = appendage.spec.max_health;
+            // Round up, so the appendage never lands just below the threshold.
+            let health = (max_health as i64 * wounded as i64 + 99) / 100;
+            let health = health.min(max_health as i64) as Health;
+            appendage.effect_history.clear();
+            appendage.effect_history.insert(
+                AppendageEffect::Abrasion,
+                health.saturating_sub(max_health).min(0),
+            );
         }
+        appendage.clear_cascade();
+        parent
+            .connected_to
+            .get_or_insert_with(Vec::new)
+            .push(appendage);
+        true
     }
 
-    pub(crate) fn get_health(&self) -> i8 {
-        let mut total_health: i8 = 100;
-        for impact in &self.effect_history {
-            total_health += impact.1;
+    fn renumber(&mut self, last: &mut u32) {
+        *last += 1;
+        self.id = AppendageId(*last);
+        for child in self.connected_to.iter_mut().flatten() {
+            child.renumber(last);
+        }
+    }
+
+    fn clear_cascade(&mut self) {
+        self.cascaded = AppendageHealth::Full;
+        self.calculate_state();
+        for child in self.connected_to.iter_mut().flatten() {
+            child.clear_cascade();
         }
-        total_health
     }
 
     fn calculate_state(&mut self) {
-        let total_health = self.get_health();
-        // idea: make this configurable per appendage type
-        self.state = if total_health >= 99 {
+        let health = self.get_health_percent();
+        let thresholds = self.spec.thresholds;
+        let state = if health >= thresholds.full {
             AppendageHealth::Full
-        } else if total_health >= 30 {
+        } else if health >= thresholds.wounded {
             AppendageHealth::Wounded
-        } else if total_health > 15 {
+        } else if health >= thresholds.disabled {
             AppendageHealth::Disabled
         } else {
-            // The idea is that once an appendage is too "damaged", it cannot be healed back to functionality.
             AppendageHealth::Amputated
         };
+        // Crushed bones do not work, however healthy the flesh around them is.
+        let crush_limit = self.get_share_of_max(CRUSH_DISABLES_AT_PERCENT);
+        let crushed = if self.get_effect_impact(AppendageEffect::Crush) <= -crush_limit {
+            AppendageHealth::Disabled
+        } else {
+            AppendageHealth::Full
+        };
+        self.state = state.max(crushed).max(self.cascaded);
     }
 
-    pub(crate) fn apply_effect(&mut self, attack_effect: AppendageEffect, health_impact: i8) {
+    /// Armor and resistances only soften effects when `mitigated` is set.
+    pub(crate) fn apply_effect(
+        &mut self,
+        attack_effect: AppendageEffect,
+        health_impact: Health,
+        mitigated: bool,
+        cause: EffectCause,
+    ) {
+        let health_impact = match attack_effect {
+            AppendageEffect::Burn if health_impact > 0 => health_impact / 2,
+            _ if mitigated => self.spec.mitigate(attack_effect, health_impact),
+            _ => health_impact,
+        };
+        // Any treatment stops a poison or infection from progressing. A fresh dose starts it again.
+        if health_impact > 0 {
+            self.treated.insert(attack_effect);
+        } else if health_impact < 0 {
+            self.treated.remove(&attack_effect);
+        }
         let entry = self.effect_history.entry(attack_effect).or_insert(0);
-        *entry += health_impact;
-        // Clamp the health impact to not exceed above 100%
+        let before = *entry;
+        *entry = entry.saturating_add(health_impact);
+        // Clamp the health impact to not heal past the appendage's max health
         if *entry > 0 {
             *entry = 0;
         }
+        let applied = entry.saturating_sub(before);
         self.calculate_state();
+        self.record(attack_effect, applied, cause);
+    }
+
+    /// Applies an effect to the target appendage in this body, following the body's cascade rules.
+    /// Takes a name or a path. Returns false if there is no such appendage.
+    pub(crate) fn apply_effect_to(
+        &mut self,
+        target: &str,
+        effect: AppendageEffect,
+        health_impact: Health,
+        cause: EffectCause,
+    ) -> bool {
+        let Some(id) = self.find(target).map(|appendage| appendage.id) else {
+            return false;
+        };
+        let rules = self.cascade;
+        self.apply_in_subtree(id, effect, health_impact, true, &rules, cause)
+            .is_some()
+    }
+
+    /// Like `apply_effect_to`, but for harm that starts inside the body, such as bleeding.
+    /// Natural armor and resistances do nothing against it.
+    pub(crate) fn apply_internal_effect_to(
+        &mut self,
+        target: &str,
+        effect: AppendageEffect,
+        health_impact: Health,
+        cause: EffectCause,
+    ) -> bool {
+        let Some(id) = self.find(target).map(|appendage| appendage.id) else {
+            return false;
+        };
+        let rules = self.cascade;
+        self.apply_in_subtree(id, effect, health_impact, false, &rules, cause)
+            .is_some()
+    }
+
+    /// Returns the damage to bubble up to the parent if the target was found in this subtree.
+    fn apply_in_subtree(
+        &mut self,
+        target: AppendageId,
+        effect: AppendageEffect,
+        health_impact: Health,
+        mitigated: bool,
+        rules: &CascadeRules,
+        cause: EffectCause,
+    ) -> Option<Health> {
+        if self.id == target {
+            self.apply_effect(effect, health_impact, mitigated, cause);
+            self.cascade_to_children(rules);
+            return Some(rules.get_bubbled(health_impact));
+        }
+        let bubbled = self.connected_to.iter_mut().flatten().find_map(|child| {
+            child.apply_in_subtree(target, effect, health_impact, mitigated, rules, cause)
+        })?;
+        if bubbled < 0 {
+            self.apply_effect(effect, bubbled, mitigated, cause);
+            self.cascade_to_children(rules);
+        }
+        // Damage only bubbles up a single level.
+        Some(0)
+    }
+
+    /// Forces this appendage's state onto everything connected to it, as far as the rules allow.
+    fn cascade_to_children(&mut self, rules: &CascadeRules) {
+        let forced = match self.state {
+            AppendageHealth::Amputated if rules.sever_children => AppendageHealth::Amputated,
+            AppendageHealth::Disabled | AppendageHealth::Amputated if rules.disable_children => {
+                AppendageHealth::Disabled
+            }
+            _ => return,
+        };
+        for child in self.connected_to.iter_mut().flatten() {
+            child.force_state(forced);
+        }
+    }
+
+    fn force_state(&mut self, forced: AppendageHealth) {
+        self.cascaded = self.cascaded.max(forced);
+        self.calculate_state();
+        for child in self.connected_to.iter_mut().flatten() {
+            child.force_state(forced);
+        }
+    }
+
+    /// Lets one tick pass for wounds that progress on their own.
+    /// Untreated infections worsen, and untreated poison in any limb seeps into this appendage, the root of the body.
+    pub(crate) fn progress_wounds(&mut self, tick: u64) {
+        let rules = self.cascade;
+        let cause = EffectCause { tick, source: None };
+        // Poison already in the torso has nowhere further to go.
+        let in_root = self.is_untreated(AppendageEffect::Poison) as Health;
+        let poisoned_limbs = self
+            .progress_infections(&rules, cause)
+            .saturating_sub(in_root);
+        if poisoned_limbs > 0 {
+            let spread = self.get_share_of_max(POISON_SPREAD_PERCENT);
+            self.worsen(
+                AppendageEffect::Poison,
+                poisoned_limbs.saturating_mul(spread),
+                &rules,
+                cause,
+            );
+        }
+    }
+
+    /// Worsens untreated infections in this subtree and counts the appendages carrying untreated poison.
+    fn progress_infections(&mut self, rules: &CascadeRules, cause: EffectCause) -> Health {
+        if self.is_untreated(AppendageEffect::Infect) {
+            let growth = self.get_share_of_max(INFECTION_GROWTH_PERCENT);
+            self.worsen(AppendageEffect::Infect, growth, rules, cause);
+        }
+        let poisoned = self.is_untreated(AppendageEffect::Poison) as Health;
+        self.connected_to
+            .iter_mut()
+            .flatten()
+            .map(|child| child.progress_infections(rules, cause))
+            .fold(poisoned, |total, count| total.saturating_add(count))
+    }
+
+    /// Deals damage from a wound progressing, without touching whether it was treated.
+    fn worsen(
+        &mut self,
+        effect: AppendageEffect,
+        amount: Health,
+        rules: &CascadeRules,
+        cause: EffectCause,
+    ) {
+        let entry = self.effect_history.entry(effect).or_insert(0);
+        let before = *entry;
+        *entry = entry.saturating_sub(amount);
+        let applied = entry.saturating_sub(before);
+        self.calculate_state();
+        self.record(effect, applied, cause);
+        self.cascade_to_children(rules);
     }
 }
 
@@ -87,40 +878,183 @@ impl Appendage {
 #[derive(Debug, Clone)]
 pub struct MorphologyBuilder {
     root: Appendage,
+    health_scale: Health,
+    next_id: u32,
 }
 
 impl MorphologyBuilder {
-    pub fn new(root_name: &str) -> Self {
+    pub fn new(root: impl Into<AppendageSpec>) -> Self {
         MorphologyBuilder {
-            root: Appendage::new(root_name),
+            root: Appendage::new(root.into()),
+            health_scale: DEFAULT_HEALTH_SCALE,
+            next_id: 1,
         }
     }
 
-    pub fn add_appendage(&mut self, parent_name: &str, child_name: &str) {
-        Self::add_to_tree(&mut self.root, parent_name, child_name);
+    /// Sets the max health of an unharmed default appendage. Every appendage's max health is scaled to match,
+    /// so a scale of 1000 gives ten times the resolution without changing how tough anything is.
+    pub fn set_health_scale(&mut self, scale: Health) {
+        self.health_scale = scale.max(1);
+    }
+
+    /// Sets how damage travels through the morphology.
+    pub fn set_cascade_rules(&mut self, rules: CascadeRules) {
+        self.root.cascade = rules;
     }
 
-    fn add_to_tree(current: &mut Appendage, parent_name: &str, child_name: &str) {
-        if current.name == parent_name {
-            let new_appendage = Appendage::new(child_name);
-            // let mut new_appendage = Appendage::default();
-            // new_appendage.name = child_name.to_owned();
-            if let Some(ref mut children) = current.connected_to {
-                children.push(new_appendage);
-            } else {
-                current.connected_to = Some(vec![new_appendage]);
+    /// Connects a new appendage to the named parent. Takes a name, or an `AppendageSpec` for anything
+    /// tougher or frailer than the default. Fails if the parent does not exist or the name is taken.
+    pub fn add_appendage(
+        &mut self,
+        parent_name: &str,
+        child: impl Into<AppendageSpec>,
+    ) -> SimutronResult<()> {
+        let child = child.into();
+        self.check_name_is_free(&child.name)?;
+        let Some(parent) = self.root.find_mut(parent_name) else {
+            return runtime_error!("There is no appendage named {} to connect to.", parent_name);
+        };
+        let mut appendage = Appendage::new(child);
+        appendage.id = AppendageId(self.next_id);
+        self.next_id += 1;
+        parent
+            .connected_to
+            .get_or_insert_with(Vec::new)
+            .push(appendage);
+        Ok(())
+    }
+
+    /// Declares what an appendage does for its creature.
+    pub fn add_capabilities(
+        &mut self,
+        appendage_name: &str,
+        capabilities: &[Capability],
+    ) -> SimutronResult<()> {
+        let Some(appendage) = self.root.find_mut(appendage_name) else {
+            return runtime_error!("There is no appendage named {}.", appendage_name);
+        };
+        appendage.capabilities.extend(capabilities.iter().copied());
+        Ok(())
+    }
+
+    /// Takes an appendage and everything connected to it out of the morphology. The root cannot be removed.
+    pub fn remove_appendage(&mut self, name: &str) -> SimutronResult<()> {
+        Self::detach(&mut self.root, name).map(|_| ())
+    }
+
+    /// Gives an appendage a new name. Fails if the new name is already taken.
+    pub fn rename_appendage(&mut self, name: &str, new_name: &str) -> SimutronResult<()> {
+        self.check_name_is_free(new_name)?;
+        let Some(appendage) = self.root.find_mut(name) else {
+            return runtime_error!("There is no appendage named {}.", name);
+        };
+        appendage.name = new_name.to_string();
+        appendage.spec.name = new_name.to_string();
+        Ok(())
+    }
+
+    /// Connects an appendage, along with everything connected to it, to a new parent.
+    /// The new parent cannot be part of the moved appendage.
+    pub fn move_appendage(&mut self, name: &str, new_parent_name: &str) -> SimutronResult<()> {
+        let parent_id = match self.root.find(new_parent_name) {
+            Some(parent) => parent.get_id(),
+            None => {
+                return runtime_error!(
+                    "There is no appendage named {} to connect to.",
+                    new_parent_name
+                );
             }
-            return;
+        };
+        if self
+            .root
+            .find(name)
+            .is_some_and(|appendage| appendage.find_by_id(parent_id).is_some())
+        {
+            return runtime_error!("{} cannot be connected to itself.", name);
         }
+        let appendage = Self::detach(&mut self.root, name)?;
+        match self.root.find_by_id_mut(parent_id) {
+            Some(parent) => {
+                parent
+                    .connected_to
+                    .get_or_insert_with(Vec::new)
+                    .push(appendage);
+                Ok(())
+            }
+            None => runtime_error!("{} went missing while moving {}.", new_parent_name, name),
+        }
+    }
 
-        if let Some(ref mut children) = current.connected_to {
-            for child in children.iter_mut() {
-                Self::add_to_tree(child, parent_name, child_name);
+    fn check_name_is_free(&self, name: &str) -> SimutronResult<()> {
+        if name.contains(PATH_SEPARATOR) {
+            return runtime_error!("Appendage names cannot contain {}.", PATH_SEPARATOR);
+        }
+        if self.root.find(name).is_some() {
+            return runtime_error!("There is already an appendage named {}.", name);
+        }
+        Ok(())
+    }
+
+    fn detach(root: &mut Appendage, name: &str) -> SimutronResult<Appendage> {
+        if root.name == name {
+            return runtime_error!(
+                "{} is the root of the morphology and cannot be removed.",
+                name
+            );
+        }
+        match Self::take_child(root, name) {
+            Some(appendage) => Ok(appendage),
+            None => runtime_error!("There is no appendage named {}.", name),
+        }
+    }
+
+    fn take_child(current: &mut Appendage, name: &str) -> Option<Appendage> {
+        let children = current.connected_to.as_mut()?;
+        if let Some(index) = children.iter().position(|child| child.name == name) {
+            let child = children.remove(index);
+            if children.is_empty() {
+                current.connected_to = None;
             }
+            return Some(child);
+        }
+        children
+            .iter_mut()
+            .find_map(|child| Self::take_child(child, name))
+    }
+
+    /// Builds the morphology after checking that every name is unique, no chain of appendages is deeper than
+    /// `MAX_MORPHOLOGY_DEPTH` and at least one appendage is vital.
+    pub fn try_build(&self) -> SimutronResult<Appendage> {
+        let mut names = HashSet::new();
+        if let Some(name) = self
+            .root
+            .get_names()
+            .into_iter()
+            .find(|name| !names.insert(name.clone()))
+        {
+            return runtime_error!("There is more than one appendage named {}.", name);
+        }
+        if self.root.get_depth() > MAX_MORPHOLOGY_DEPTH {
+            return runtime_error!(
+                "{} has appendages more than {} deep.",
+                self.root.name,
+                MAX_MORPHOLOGY_DEPTH
+            );
+        }
+        let root = self.build();
+        if root.count_working(Capability::Vital) == 0 {
+            return runtime_error!("{} has no vital appendage.", root.name);
         }
+        Ok(root)
     }
 
+    /// Builds the morphology as it is, without any checks.
     pub fn build(&self) -> Appendage {
-        self.root.clone()
+        let mut root = self.root.clone();
+        root.scale_health(self.health_scale);
+        let mut counts = HashMap::new();
+        root.count_capabilities(&mut counts);
+        root.base_capabilities = counts;
+        root
     }
 }
@@ -1,4 +1,5 @@
-use crate::creatures::creature_builder::Appendage;
+use crate::creatures::components::VitalState;
+use crate::creatures::creature_builder::{Appendage, AppendageId, Capability};
 use crate::ecs::component::Component;
 
 pub mod components;
@@ -16,26 +17,108 @@ pub struct Creature {
 
 impl Component for Creature {}
 
+/// At or below this health percentage in a vital appendage, or overall, a creature is dying.
+const DYING_AT: u8 = 40;
+/// At or below this health percentage in a vital appendage, or overall, a creature passes out.
+const UNCONSCIOUS_AT: u8 = 60;
+
 impl Creature {
-    pub fn get_character_health(&self) -> u8 {
-        let (appendages, total_health) = Self::calculate_corpus_health(self.corpus.clone());
-        let average_health = if appendages > 0 {
-            total_health / (appendages)
+    /// Looks for an appendage by name, or by path from the root such as `Torso/Left Arm/Left Hand`.
+    pub fn find(&self, target: &str) -> Option<&Appendage> {
+        self.corpus.find(target)
+    }
+
+    pub fn find_mut(&mut self, target: &str) -> Option<&mut Appendage> {
+        self.corpus.find_mut(target)
+    }
+
+    pub fn find_by_id(&self, id: AppendageId) -> Option<&Appendage> {
+        self.corpus.find_by_id(id)
+    }
+
+    /// Returns every appendage of the creature, parents first.
+    pub fn iter(&self) -> impl Iterator<Item = &Appendage> {
+        self.corpus.iter()
+    }
+
+    /// Returns the appendage the target is connected to, or None for the root.
+    pub fn parent_of(&self, target: &str) -> Option<&Appendage> {
+        self.corpus.parent_of(target)
+    }
+
+    /// Returns the path from the root to the target, e.g. `Torso/Left Arm/Left Hand`.
+    pub fn get_path(&self, target: &str) -> Option<String> {
+        self.corpus.get_path(target)
+    }
+
+    /// A creature whose body has no health left, or who lost a vital appendage, is dead.
+    /// Otherwise its weakest vital appendage, or its overall health if that is lower, decides whether it is
+    /// dying, unconscious or up and about. Morphologies without vital appendages go by the root instead.
+    pub fn get_vital_state(&self) -> VitalState {
+        let health = self.get_character_health();
+        let vital_lost = self.corpus.count_working(Capability::Vital)
+            < self.corpus.get_base_capability(Capability::Vital);
+        if health == 0 || vital_lost {
+            return VitalState::Dead;
+        }
+        let lowest = self.get_vital_appendage().get_health_percent().min(health);
+        if lowest <= DYING_AT {
+            VitalState::Dying
+        } else if lowest <= UNCONSCIOUS_AT {
+            VitalState::Unconscious
         } else {
-            0
-        };
+            VitalState::Alive
+        }
+    }
+
+    /// Returns the vital appendage closest to failing, or the root if none are declared.
+    pub fn get_vital_appendage(&self) -> &Appendage {
+        self.corpus
+            .find_weakest(Capability::Vital)
+            .unwrap_or(&self.corpus)
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.get_vital_state() != VitalState::Dead
+    }
+
+    /// Only conscious creatures can take turns and act.
+    pub fn can_act(&self) -> bool {
+        self.get_vital_state() == VitalState::Alive
+    }
+
+    /// Returns true if the creature still has a working appendage with the capability.
+    /// A morphology that never declared the capability is not limited by it.
+    pub fn is_capable(&self, capability: Capability) -> bool {
+        self.corpus.get_base_capability(capability) == 0
+            || self.corpus.count_working(capability) > 0
+    }
+
+    /// Returns the share of the creature's appendages with a capability that still work, from 0 to 1.
+    /// A morphology that never declared the capability always gets 1.
+    pub fn get_capability_ratio(&self, capability: Capability) -> f64 {
+        let base = self.corpus.get_base_capability(capability);
+        if base == 0 {
+            return 1.0;
+        }
+        (self.corpus.count_working(capability) as f64 / base as f64).min(1.0)
+    }
+
+    pub fn get_character_health(&self) -> u8 {
+        let (appendages, total_health) = Self::calculate_corpus_health(&self.corpus);
+        let average_health = total_health.checked_div(appendages).unwrap_or(0);
         average_health as u8
     }
 
-    fn calculate_corpus_health(appendage: Appendage) -> (u32, u32) {
+    fn calculate_corpus_health(appendage: &Appendage) -> (u32, u32) {
         let mut total_appendages = 1u32;
         if appendage.connected_to.is_none() {
-            return (total_appendages, appendage.get_health() as u32);
+            return (total_appendages, appendage.get_health_percent() as u32);
         }
-        let mut total_health = appendage.get_health() as u32;
+        let mut total_health = appendage.get_health_percent() as u32;
         if let Some(children) = &appendage.connected_to {
             for child in children {
-                let (child_count, child_health) = Self::calculate_corpus_health(child.clone());
+                let (child_count, child_health) = Self::calculate_corpus_health(child);
                 total_appendages += child_count;
                 total_health += child_health;
             }
@@ -1,17 +1,50 @@
 use crate::creatures::Appendage;
-use crate::prelude::MorphologyBuilder;
+use crate::errors::SimutronResult;
+use crate::prelude::{AppendageSpec, Capability, EquipmentSlot, MorphologyBuilder};
 
 pub fn humanoid_corpus() -> Appendage {
-    let mut humanoid = MorphologyBuilder::new("Torso");
-    humanoid.add_appendage("Torso", "Left Arm");
-    humanoid.add_appendage("Torso", "Right Arm");
-    humanoid.add_appendage("Left Arm", "Left Hand");
-    humanoid.add_appendage("Right Arm", "Right Hand");
-    humanoid.add_appendage("Torso", "Left Leg");
-    humanoid.add_appendage("Torso", "Right Leg");
-    humanoid.add_appendage("Left Leg", "Left Foot");
-    humanoid.add_appendage("Right Leg", "Right Foot");
-    humanoid.add_appendage("Torso", "Head");
+    // The tree is fixed, so building it can only fail if this file is broken.
+    build_humanoid().expect("the humanoid morphology is valid")
+}
+
+fn build_humanoid() -> SimutronResult<Appendage> {
+    // Hit weights add up to 100, so each one is the chance in percent of a random blow landing there.
+    let part = |name: &str, hit_weight: u32| AppendageSpec::new(name).with_hit_weight(hit_weight);
+    let hand = [EquipmentSlot::Weapon, EquipmentSlot::Gloves];
+    let mut humanoid =
+        MorphologyBuilder::new(part("Torso", 40).with_slots(&[EquipmentSlot::Armor]));
+    humanoid.add_appendage("Torso", part("Left Arm", 10))?;
+    humanoid.add_appendage("Torso", part("Right Arm", 10))?;
+    humanoid.add_appendage(
+        "Left Arm",
+        part("Left Hand", 5)
+            .with_slots(&hand)
+            .with_slots(&[EquipmentSlot::Shield]),
+    )?;
+    humanoid.add_appendage("Right Arm", part("Right Hand", 5).with_slots(&hand))?;
+    humanoid.add_appendage("Torso", part("Left Leg", 8))?;
+    humanoid.add_appendage("Torso", part("Right Leg", 8))?;
+    humanoid.add_appendage(
+        "Left Leg",
+        part("Left Foot", 2).with_slots(&[EquipmentSlot::Boots]),
+    )?;
+    humanoid.add_appendage(
+        "Right Leg",
+        part("Right Foot", 2).with_slots(&[EquipmentSlot::Boots]),
+    )?;
+    humanoid.add_appendage(
+        "Torso",
+        part("Head", 10).with_slots(&[EquipmentSlot::Helmet]),
+    )?;
+
+    humanoid.add_capabilities("Left Hand", &[Capability::Grasp])?;
+    humanoid.add_capabilities("Right Hand", &[Capability::Grasp])?;
+    humanoid.add_capabilities("Left Foot", &[Capability::Locomotion])?;
+    humanoid.add_capabilities("Right Foot", &[Capability::Locomotion])?;
+    humanoid.add_capabilities(
+        "Head",
+        &[Capability::Sight, Capability::Speech, Capability::Vital],
+    )?;
 
-    humanoid.build()
+    humanoid.try_build()
 }
@@ -0,0 +1,30 @@
+//! Common bodies that ship with the crate, stored in the morphology file format.
+use crate::creatures::creature_builder::MorphologyBuilder;
+use crate::creatures::morphologies::loader::parse_morphology;
+use crate::errors::{SimutronError, SimutronResult};
+use crate::runtime_error;
+
+const LIBRARY: [(&str, &str); 6] = [
+    ("Quadruped", include_str!("library/quadruped.json")),
+    ("Avian", include_str!("library/avian.json")),
+    ("Serpentine", include_str!("library/serpentine.json")),
+    ("Insectoid", include_str!("library/insectoid.json")),
+    ("Dragon", include_str!("library/dragon.json")),
+    ("Arachnid", include_str!("library/arachnid.json")),
+];
+
+/// Names of every bundled morphology.
+pub fn get_names() -> impl Iterator<Item = &'static str> {
+    LIBRARY.iter().map(|(name, _)| *name)
+}
+
+/// Looks up a bundled morphology by name, ignoring case.
+pub fn get_morphology(name: &str) -> SimutronResult<MorphologyBuilder> {
+    match LIBRARY
+        .iter()
+        .find(|(entry, _)| entry.eq_ignore_ascii_case(name))
+    {
+        Some((_, source)) => parse_morphology(source),
+        None => runtime_error!("There is no bundled morphology named {}.", name),
+    }
+}
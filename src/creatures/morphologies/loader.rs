@@ -0,0 +1,121 @@
+//! Morphologies described as data instead of code.
+//!
+//! A morphology file is JSON. The body is a tree of appendages, each of which may set the same stats
+//! as an `AppendageSpec` plus its capabilities and the appendages connected to it:
+//!
+//! ```json
+//! {
+//!     "name": "Snail",
+//!     "health_scale": 100,
+//!     "cascade": { "bubble_up_percent": 25 },
+//!     "body": {
+//!         "name": "Foot",
+//!         "capabilities": ["Locomotion", "Vital"],
+//!         "slots": ["Armor"],
+//!         "children": [
+//!             { "name": "Shell", "armor": 5, "hit_weight": 40, "resistances": { "Crush": 50 } },
+//!             { "name": "Eye Stalk", "max_health": 20, "capabilities": ["Sight"] }
+//!         ]
+//!     }
+//! }
+//! ```
+use crate::creatures::creature_builder::{
+    AppendageEffect, AppendageSpec, Capability, CascadeRules, Health, HealthThresholds,
+    MorphologyBuilder,
+};
+use crate::equipment::components::EquipmentSlot;
+use crate::errors::{SimutronError, SimutronResult};
+use crate::runtime_error;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MorphologyFile {
+    name: String,
+    health_scale: Option<Health>,
+    cascade: Option<CascadeRules>,
+    body: AppendageFile,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AppendageFile {
+    name: String,
+    max_health: Option<Health>,
+    thresholds: Option<HealthThresholds>,
+    armor: Option<Health>,
+    hit_weight: Option<u32>,
+    #[serde(default)]
+    resistances: HashMap<AppendageEffect, i8>,
+    #[serde(default)]
+    capabilities: Vec<Capability>,
+    #[serde(default)]
+    slots: Vec<EquipmentSlot>,
+    #[serde(default)]
+    children: Vec<AppendageFile>,
+}
+
+impl AppendageFile {
+    fn get_spec(&self) -> AppendageSpec {
+        let mut spec = AppendageSpec::new(&self.name);
+        if let Some(max_health) = self.max_health {
+            spec = spec.with_max_health(max_health);
+        }
+        if let Some(thresholds) = self.thresholds {
+            spec = spec.with_thresholds(thresholds);
+        }
+        if let Some(armor) = self.armor {
+            spec = spec.with_armor(armor);
+        }
+        if let Some(hit_weight) = self.hit_weight {
+            spec = spec.with_hit_weight(hit_weight);
+        }
+        for (effect, percent) in &self.resistances {
+            spec = spec.with_resistance(*effect, *percent);
+        }
+        spec.with_slots(&self.slots)
+    }
+
+    fn add_children(&self, builder: &mut MorphologyBuilder) -> SimutronResult<()> {
+        for child in &self.children {
+            builder.add_appendage(&self.name, child.get_spec())?;
+            builder.add_capabilities(&child.name, &child.capabilities)?;
+            child.add_children(builder)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a morphology from JSON. Returns a builder, so the body can still be changed before it is built.
+pub fn parse_morphology(source: &str) -> SimutronResult<MorphologyBuilder> {
+    let file: MorphologyFile = match serde_json::from_str(source) {
+        Ok(file) => file,
+        Err(error) => return runtime_error!("Invalid morphology file: {}", error),
+    };
+    let mut builder = MorphologyBuilder::new(file.body.get_spec());
+    builder.add_capabilities(&file.body.name, &file.body.capabilities)?;
+    if let Err(error) = file.body.add_children(&mut builder) {
+        return match *error {
+            SimutronError::Runtime(message) => {
+                runtime_error!("Invalid morphology {}: {}", file.name, message)
+            }
+            error => Err(Box::new(error)),
+        };
+    }
+    if let Some(rules) = file.cascade {
+        builder.set_cascade_rules(rules);
+    }
+    if let Some(scale) = file.health_scale {
+        builder.set_health_scale(scale);
+    }
+    Ok(builder)
+}
+
+/// Reads a morphology from a JSON file.
+pub fn load_morphology(path: impl AsRef<Path>) -> SimutronResult<MorphologyBuilder> {
+    let source =
+        std::fs::read_to_string(path).map_err(|error| Box::new(SimutronError::from(error)))?;
+    parse_morphology(&source)
+}
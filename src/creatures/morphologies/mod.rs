@@ -1,2 +1,4 @@
 pub mod humanoid;
+pub mod library;
+pub mod loader;
 pub mod roper;
@@ -1,10 +1,16 @@
-use crate::creatures::components::CreatureActions;
-use crate::creatures::creature_builder::Appendage;
+use crate::creatures::components::{
+    Corpse, CreatureActions, DeathRules, MovementBudget, SeveredAppendage, VitalEvents, VitalState,
+    VitalStateChanged, Vitals,
+};
+use crate::creatures::creature_builder::{apply_armor, EffectCause};
 use crate::creatures::Creature;
+use crate::ecs::components::{Inventory, Position, PropHealth};
 use crate::ecs::entity::Entity;
 use crate::ecs::world::World;
+use crate::equipment::components::Equipment;
 use crate::errors::{SimutronError, SimutronResult};
 use crate::runtime_error;
+use log::debug;
 
 impl World {
     pub fn get_creature_by_name(&self, name: &str) -> Option<(Entity, &Creature)> {
@@ -20,26 +26,237 @@ impl World {
         }
     }
 
-    fn get_appendage_ref_by_name(appendage: &mut Appendage, action: CreatureActions) {
-        if appendage.name == action.target {
-            appendage.apply_effect(action.effect, action.impact);
-            return;
+    pub fn apply_creature_action(&mut self, action: &CreatureActions) -> SimutronResult<Creature> {
+        let cause = EffectCause {
+            tick: self.get_clock().ticks,
+            source: Some(action.from),
+        };
+        let target = match self.get_component::<Creature>(action.to) {
+            Some(creature) => match creature.find(&action.target) {
+                Some(appendage) => appendage.get_id(),
+                None => return runtime_error!("{} has no {}.", creature.name, action.target),
+            },
+            None => return runtime_error!("Creature not found in world."),
+        };
+        // Whatever the appendage is wearing takes the edge off first.
+        let armor = self.get_equipped_armor(action.to, target);
+        let impact = apply_armor(action.effect, action.impact, armor);
+        if let Some(creature) = self.get_component_mut::<Creature>(action.to) {
+            creature
+                .corpus
+                .apply_effect_to(&action.target, action.effect, impact, cause);
+        }
+        if action.impact > 0 {
+            self.treat_statuses(action.to, action.effect, &action.target);
+        }
+        self.sever_amputated(action.to)?;
+        let creature = match self.get_component::<Creature>(action.to) {
+            Some(creature) => creature.clone(),
+            None => return runtime_error!("Creature not found in world."),
+        };
+        self.update_vital_state(action.to)?;
+        Ok(creature)
+    }
+
+    /// Checks whether a creature's vital state changed since it was last checked, and reacts if it did.
+    /// A dead creature leaves the running encounter and becomes a corpse if the world's `DeathRules` ask for it.
+    /// The unconscious and the dying stay in the encounter and lose their turns until they come round. Every change is queued as a `VitalStateChanged` event.
+    pub fn update_vital_state(
+        &mut self,
+        creature: Entity,
+    ) -> SimutronResult<Option<VitalStateChanged>> {
+        let (name, current) = match self.get_component::<Creature>(creature) {
+            Some(body) => (body.name.clone(), body.get_vital_state()),
+            None => return runtime_error!("Creature not found in world."),
+        };
+        let previous = self
+            .get_component::<Vitals>(creature)
+            .map(|vitals| vitals.state)
+            .unwrap_or_default();
+        if previous == current {
+            return Ok(None);
         }
-        if let Some(ref mut children) = appendage.connected_to {
-            for child in children.iter_mut() {
-                Self::get_appendage_ref_by_name(child, action.clone());
+        debug!("{} went from {:?} to {:?}", name, previous, current);
+        self.add_component(creature, Vitals { state: current });
+        let event = VitalStateChanged {
+            creature,
+            previous,
+            current,
+        };
+        match self.get_resource_mut::<VitalEvents>() {
+            Some(events) => events.events.push(event),
+            None => self.insert_resource(VitalEvents {
+                events: vec![event],
+            }),
+        }
+
+        if current == VitalState::Dead && self.is_in_encounter(creature) {
+            self.leave_encounter(creature)?;
+        }
+        let leave_corpses = self
+            .get_resource::<DeathRules>()
+            .is_some_and(|rules| rules.leave_corpses);
+        if current == VitalState::Dead && leave_corpses {
+            self.make_corpse(creature)?;
+        }
+        Ok(Some(event))
+    }
+
+    /// Takes every vital state change queued so far, oldest first.
+    pub fn drain_vital_events(&mut self) -> Vec<VitalStateChanged> {
+        match self.get_resource_mut::<VitalEvents>() {
+            Some(events) => std::mem::take(&mut events.events),
+            None => Vec::new(),
+        }
+    }
+
+    /// Replaces a creature with a corpse prop where it stands. The corpse keeps the creature's inventory and body,
+    /// and everything the creature had equipped ends up in the corpse's inventory.
+    /// Returns the corpse.
+    pub fn make_corpse(&mut self, creature: Entity) -> SimutronResult<Entity> {
+        let body = match self.get_component::<Creature>(creature) {
+            Some(body) => body.clone(),
+            None => return runtime_error!("Creature not found in world."),
+        };
+        let corpse = self.create_prop(
+            &format!("{}'s corpse", body.name),
+            &format!("The lifeless body of {}.", body.name),
+        );
+        self.add_component(corpse, PropHealth::new(body.get_character_health()));
+        if let Some(position) = self.get_component::<Position>(creature).copied() {
+            self.set_position(corpse, position);
+        }
+        let mut inventory = self
+            .get_component::<Inventory>(creature)
+            .cloned()
+            .unwrap_or_else(Inventory::new);
+        if let Some(equipment) = self.get_component::<Equipment>(creature) {
+            inventory
+                .items
+                .extend(equipment.get_items().map(|(_, _, item)| item.0));
+        }
+        self.add_component(corpse, inventory);
+        debug!("{} is now a corpse", body.name);
+        self.add_component(corpse, Corpse { creature: body });
+        self.despawn_entity(creature);
+        Ok(corpse)
+    }
+
+    /// Cuts amputated appendages off a creature and drops each one as a prop where the creature stands.
+    /// Anything equipped on a severed appendage goes with it, into the prop's inventory.
+    /// Returns the props spawned.
+    pub(crate) fn sever_amputated(&mut self, creature: Entity) -> SimutronResult<Vec<Entity>> {
+        let (name, severed) = match self.get_component_mut::<Creature>(creature) {
+            Some(body) => (body.name.clone(), body.corpus.detach_amputated()),
+            None => return runtime_error!("Creature not found in world."),
+        };
+        let position = self.get_component::<Position>(creature).copied();
+        let mut props = Vec::new();
+        for (attached_to, appendage) in severed {
+            let prop = self.create_prop(
+                &format!("{}'s {}", name, appendage.get_name()),
+                &format!(
+                    "The severed {} of {}.",
+                    appendage.get_name().to_lowercase(),
+                    name
+                ),
+            );
+            self.add_component(prop, PropHealth::new(appendage.get_health_percent()));
+            if let Some(position) = position {
+                self.set_position(prop, position);
             }
+            let ids: Vec<_> = appendage.iter().map(|part| part.get_id()).collect();
+            for id in ids {
+                let dropped = self.drop_equipment(creature, id);
+                if let Some(inventory) = self.get_component_mut::<Inventory>(prop) {
+                    inventory.items.extend(dropped.iter().map(|item| item.0));
+                }
+            }
+            debug!("{}'s {} was severed", name, appendage.get_name());
+            self.add_component(
+                prop,
+                SeveredAppendage {
+                    appendage,
+                    owner: creature,
+                    attached_to,
+                },
+            );
+            props.push(prop);
         }
+        Ok(props)
     }
 
-    pub fn apply_creature_action(&mut self, action: &CreatureActions) -> SimutronResult<Creature> {
-        let creature = self.get_component_mut::<Creature>(action.to);
-        if let Some(creature) = creature {
-            let root = &mut creature.corpus;
-            Self::get_appendage_ref_by_name(root, action.clone());
-            Ok(creature.clone())
-        } else {
-            runtime_error!("Creature not found in world.")
+    /// Sews a severed appendage back onto a creature, where it was attached before.
+    /// Any creature with a matching appendage will do, not just the original owner.
+    /// On another creature the part is given new ids, so it cannot be mistaken for one of the host's own appendages.
+    /// The prop is removed from the world, and from any inventory holding it.
+    pub fn reattach_appendage(&mut self, creature: Entity, severed: Entity) -> SimutronResult<()> {
+        let part = match self.get_component::<SeveredAppendage>(severed) {
+            Some(part) => part.clone(),
+            None => return runtime_error!("Entity {:?} is not a severed appendage.", severed),
+        };
+        let body = match self.get_component_mut::<Creature>(creature) {
+            Some(body) => body,
+            None => return runtime_error!("Creature not found in world."),
+        };
+        if body.corpus.find(part.appendage.get_name()).is_some() {
+            return runtime_error!("{} already has a {}.", body.name, part.appendage.get_name());
+        }
+        if !body.corpus.attach(&part.attached_to, part.appendage) {
+            return runtime_error!("{} has no {} to attach to.", body.name, part.attached_to);
+        }
+        for holder in self.get_entities_with::<Inventory>() {
+            if let Some(inventory) = self.get_component_mut::<Inventory>(holder) {
+                inventory.items.retain(|item| *item != severed.0);
+            }
+        }
+        self.despawn_entity(severed);
+        Ok(())
+    }
+
+    /// Clears a creature's movement budget for a new turn and returns it.
+    pub fn reset_movement_budget(&mut self, creature: Entity) -> SimutronResult<MovementBudget> {
+        if self.get_component::<Creature>(creature).is_none() {
+            return runtime_error!("Entity {:?} is not a creature.", creature);
+        }
+        let budget = match self.get_component_mut::<MovementBudget>(creature) {
+            Some(budget) => {
+                budget.reset();
+                budget.clone()
+            }
+            None => {
+                let budget = MovementBudget::new();
+                self.add_component(creature, budget.clone());
+                budget
+            }
+        };
+        Ok(budget)
+    }
+
+    /// The movement a creature has left this turn at its current speed.
+    /// Outside an encounter there are no turns, so this is always the full speed.
+    pub fn get_remaining_movement(&self, creature: Entity) -> SimutronResult<u32> {
+        let speed = Self::get_budget_speed(&self.get_movement_speeds(creature)?);
+        if !self.is_in_encounter(creature) {
+            return Ok(speed);
+        }
+        Ok(self
+            .get_component::<MovementBudget>(creature)
+            .map_or(speed, |budget| budget.get_remaining(speed)))
+    }
+
+    /// The creature gives up its action to move again this turn, doubling its movement.
+    /// Dashing more than once stacks.
+    pub fn dash(&mut self, creature: Entity) -> SimutronResult<MovementBudget> {
+        if self.get_component::<MovementBudget>(creature).is_none() {
+            self.reset_movement_budget(creature)?;
+        }
+        match self.get_component_mut::<MovementBudget>(creature) {
+            Some(budget) => {
+                budget.dash();
+                Ok(budget.clone())
+            }
+            None => runtime_error!("Creature {:?} has no movement budget.", creature),
         }
     }
 }
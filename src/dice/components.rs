@@ -0,0 +1,154 @@
+use crate::dice::expression::{DiceExpression, DiceTerm, Keep};
+use crate::ecs::resource::Resource;
+use crate::errors::SimutronResult;
+
+/// A single die thrown as part of a roll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DieResult {
+    pub sides: u32,
+    pub value: u32,
+    /// False if a keep-highest or keep-lowest rule threw the die away.
+    pub kept: bool,
+}
+
+/// The outcome of rolling a dice expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiceRoll {
+    /// The expression as it was written, e.g. `1d20adv`.
+    /// Rolling an already parsed `DiceExpression` records its normalized form, e.g. `2d20kh1`.
+    pub expression: String,
+    /// Every die thrown, in order.
+    pub dice: Vec<DieResult>,
+    pub total: i32,
+}
+
+impl DiceRoll {
+    /// Returns true if any kept die landed on its highest face. Handy for spotting a natural 20.
+    pub fn is_max(&self) -> bool {
+        self.dice
+            .iter()
+            .any(|die| die.kept && die.value == die.sides)
+    }
+
+    /// Returns true if any kept die landed on a 1.
+    pub fn is_min(&self) -> bool {
+        self.dice.iter().any(|die| die.kept && die.value == 1)
+    }
+}
+
+/// The world's source of randomness. Insert it as a resource with a seed to make a game reproducible:
+/// the same seed and the same sequence of rolls always give the same results.
+/// Every roll is written to the log.
+#[derive(Debug, Clone)]
+pub struct Dice {
+    seed: u64,
+    state: u64,
+    log: Vec<DiceRoll>,
+}
+
+impl Resource for Dice {}
+
+impl Dice {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            state: seed,
+            log: Vec::new(),
+        }
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns every roll made so far, oldest first.
+    pub fn get_log(&self) -> &[DiceRoll] {
+        &self.log
+    }
+
+    /// Empties the log and hands back what was in it.
+    pub fn take_log(&mut self) -> Vec<DiceRoll> {
+        std::mem::take(&mut self.log)
+    }
+
+    /// Returns the next raw number from the generator (SplitMix64).
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number from 1 to `sides`, every face equally likely. This is not logged.
+    pub fn roll_die(&mut self, sides: u32) -> u32 {
+        let sides = sides.max(1) as u64;
+        // Throw away the top of the range that does not divide evenly, so no face is favoured.
+        let zone = u64::MAX - u64::MAX % sides;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return (value % sides) as u32 + 1;
+            }
+        }
+    }
+
+    /// Rolls a parsed expression and logs the result.
+    pub fn roll_expression(&mut self, expression: &DiceExpression) -> DiceRoll {
+        self.roll_terms(expression, expression.to_string())
+    }
+
+    /// Parses and rolls an expression such as `2d6+3`, and logs the result.
+    pub fn roll(&mut self, expression: &str) -> SimutronResult<DiceRoll> {
+        let parsed = DiceExpression::parse(expression)?;
+        Ok(self.roll_terms(&parsed, expression.to_string()))
+    }
+
+    /// Rolls every term of `expression`, recording the roll under `written`. Totals saturate rather than overflow.
+    fn roll_terms(&mut self, expression: &DiceExpression, written: String) -> DiceRoll {
+        let mut roll = DiceRoll {
+            expression: written,
+            dice: Vec::new(),
+            total: 0,
+        };
+        for term in &expression.terms {
+            match term {
+                DiceTerm::Modifier(value) => roll.total = roll.total.saturating_add(*value),
+                DiceTerm::Dice {
+                    count,
+                    sides,
+                    keep,
+                    negative,
+                } => {
+                    let mut dice: Vec<DieResult> = (0..*count)
+                        .map(|_| DieResult {
+                            sides: *sides,
+                            value: self.roll_die(*sides),
+                            kept: true,
+                        })
+                        .collect();
+                    let mut ranked: Vec<usize> = (0..dice.len()).collect();
+                    ranked.sort_by_key(|index| dice[*index].value);
+                    let dropped = match keep {
+                        Keep::All => &ranked[..0],
+                        Keep::Highest(k) => &ranked[..ranked.len().saturating_sub(*k as usize)],
+                        Keep::Lowest(k) => &ranked[(*k as usize).min(ranked.len())..],
+                    };
+                    for index in dropped {
+                        dice[*index].kept = false;
+                    }
+                    let sum = dice
+                        .iter()
+                        .filter(|die| die.kept)
+                        .fold(0i32, |sum, die| sum.saturating_add(die.value as i32));
+                    roll.total = roll
+                        .total
+                        .saturating_add(if *negative { -sum } else { sum });
+                    roll.dice.extend(dice);
+                }
+            }
+        }
+        self.log.push(roll.clone());
+        roll
+    }
+}
@@ -0,0 +1,208 @@
+use crate::errors::{SimutronError, SimutronResult};
+use crate::runtime_error;
+use std::fmt;
+
+/// The most dice a single term may roll, counting the extra dice of advantage and disadvantage.
+pub const MAX_DICE: u32 = 1000;
+/// The most sides a die may have.
+pub const MAX_SIDES: u32 = 1000;
+
+/// Which dice of a group count towards the total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    All,
+    Highest(u32),
+    Lowest(u32),
+}
+
+/// One part of a dice expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiceTerm {
+    /// `count` dice with `sides` sides each. Negative terms are subtracted from the total.
+    Dice {
+        count: u32,
+        sides: u32,
+        keep: Keep,
+        negative: bool,
+    },
+    /// A flat number added to the total.
+    Modifier(i32),
+}
+
+/// A parsed dice expression such as `2d6+3`, `4d6kh3`, `1d20adv` or `d8-1`.
+///
+/// Supported terms, joined with `+` or `-`:
+/// - `NdM` rolls N dice with M sides. N defaults to 1.
+/// - `NdMkhK` / `NdMklK` keeps the highest / lowest K dice.
+/// - `dMadv` / `dMdis` rolls twice and keeps the higher / lower result.
+/// - Plain numbers are flat modifiers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiceExpression {
+    pub terms: Vec<DiceTerm>,
+}
+
+impl DiceExpression {
+    pub fn parse(expression: &str) -> SimutronResult<Self> {
+        let cleaned: String = expression
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+        if cleaned.is_empty() {
+            return runtime_error!("Dice expression is empty.");
+        }
+
+        let mut terms = Vec::new();
+        let mut negative = false;
+        let mut start = 0;
+        for (index, c) in cleaned.char_indices() {
+            if (c == '+' || c == '-') && index > start {
+                terms.push(Self::parse_term(
+                    &cleaned[start..index],
+                    negative,
+                    expression,
+                )?);
+                negative = c == '-';
+                start = index + 1;
+            } else if (c == '+' || c == '-') && index == start {
+                if index != 0 {
+                    return runtime_error!("Dice expression '{}' has a dangling sign.", expression);
+                }
+                negative = c == '-';
+                start = index + 1;
+            }
+        }
+        if start >= cleaned.len() {
+            return runtime_error!("Dice expression '{}' has a dangling sign.", expression);
+        }
+        terms.push(Self::parse_term(&cleaned[start..], negative, expression)?);
+        Ok(DiceExpression { terms })
+    }
+
+    fn parse_term(term: &str, negative: bool, expression: &str) -> SimutronResult<DiceTerm> {
+        let (count, rest) = match term.split_once('d') {
+            Some((count, rest)) => (count, rest),
+            None => {
+                return match term.parse::<i32>() {
+                    Ok(value) if negative => Ok(DiceTerm::Modifier(-value)),
+                    Ok(value) => Ok(DiceTerm::Modifier(value)),
+                    Err(_) => runtime_error!("Cannot read '{}' in '{}'.", term, expression),
+                };
+            }
+        };
+        let count = match count {
+            "" => 1,
+            count => match count.parse::<u32>() {
+                Ok(count) => count,
+                Err(_) => return runtime_error!("Cannot read '{}' in '{}'.", term, expression),
+            },
+        };
+
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let sides = match rest[..digits].parse::<u32>() {
+            Ok(sides) if sides > MAX_SIDES => {
+                return runtime_error!(
+                    "Dice in '{}' cannot have more than {} sides.",
+                    expression,
+                    MAX_SIDES
+                );
+            }
+            Ok(sides) if sides > 0 => sides,
+            _ => return runtime_error!("Dice in '{}' need a number of sides.", expression),
+        };
+        let (count, keep) = match &rest[digits..] {
+            "" => (count, Keep::All),
+            "adv" => (count.saturating_mul(2), Keep::Highest(count)),
+            "dis" => (count.saturating_mul(2), Keep::Lowest(count)),
+            suffix => {
+                let parsed = suffix
+                    .strip_prefix("kh")
+                    .map(|k| (true, k))
+                    .or_else(|| suffix.strip_prefix("kl").map(|k| (false, k)));
+                match parsed.map(|(highest, k)| (highest, k.parse::<u32>())) {
+                    Some((true, Ok(k))) => (count, Keep::Highest(k.min(count))),
+                    Some((false, Ok(k))) => (count, Keep::Lowest(k.min(count))),
+                    _ => return runtime_error!("Cannot read '{}' in '{}'.", term, expression),
+                }
+            }
+        };
+        if count == 0 {
+            return runtime_error!("Cannot roll zero dice in '{}'.", expression);
+        }
+        if count > MAX_DICE {
+            return runtime_error!(
+                "Cannot roll more than {} dice at once in '{}'.",
+                MAX_DICE,
+                expression
+            );
+        }
+        Ok(DiceTerm::Dice {
+            count,
+            sides,
+            keep,
+            negative,
+        })
+    }
+
+    /// The lowest and highest totals the expression can produce. Totals beyond what an i32 holds are clamped.
+    pub fn get_range(&self) -> (i32, i32) {
+        let mut range: (i32, i32) = (0, 0);
+        for term in &self.terms {
+            let (low, high) = match term {
+                DiceTerm::Modifier(value) => (*value, *value),
+                DiceTerm::Dice {
+                    count,
+                    sides,
+                    keep,
+                    negative,
+                } => {
+                    let kept = match keep {
+                        Keep::All => *count,
+                        Keep::Highest(k) | Keep::Lowest(k) => *k,
+                    } as i32;
+                    let most = kept.saturating_mul(*sides as i32);
+                    if *negative {
+                        (-most, -kept)
+                    } else {
+                        (kept, most)
+                    }
+                }
+            };
+            range = (range.0.saturating_add(low), range.1.saturating_add(high));
+        }
+        range
+    }
+}
+
+impl fmt::Display for DiceExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, term) in self.terms.iter().enumerate() {
+            match term {
+                DiceTerm::Modifier(value) if index == 0 => write!(f, "{}", value)?,
+                DiceTerm::Modifier(value) if *value < 0 => write!(f, "-{}", -value)?,
+                DiceTerm::Modifier(value) => write!(f, "+{}", value)?,
+                DiceTerm::Dice {
+                    count,
+                    sides,
+                    keep,
+                    negative,
+                } => {
+                    if *negative {
+                        write!(f, "-")?;
+                    } else if index > 0 {
+                        write!(f, "+")?;
+                    }
+                    write!(f, "{}d{}", count, sides)?;
+                    match keep {
+                        Keep::All => {}
+                        Keep::Highest(k) => write!(f, "kh{}", k)?,
+                        Keep::Lowest(k) => write!(f, "kl{}", k)?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+/// Randomness for the engine. A seeded generator lives on the World as a resource
+/// and rolls dice expressions such as `2d6+3` or `1d20adv`.
+pub mod components;
+pub mod expression;
+pub(crate) mod systems;
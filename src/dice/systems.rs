@@ -0,0 +1,28 @@
+use crate::dice::components::{Dice, DiceRoll};
+use crate::ecs::world::World;
+use crate::errors::{SimutronError, SimutronResult};
+use crate::runtime_error;
+use uuid::Uuid;
+
+impl World {
+    /// Replaces the world's dice with a fresh set seeded with `seed`.
+    pub fn seed_dice(&mut self, seed: u64) {
+        self.insert_resource(Dice::new(seed));
+    }
+
+    /// Parses and rolls a dice expression such as `2d6+3` with the world's dice.
+    pub fn roll(&mut self, expression: &str) -> SimutronResult<DiceRoll> {
+        self.with_dice(|dice| dice.roll(expression))?
+    }
+
+    /// Hands the world's dice to `f`. A world that was never seeded gets a random seed on first use.
+    pub(crate) fn with_dice<T>(&mut self, f: impl FnOnce(&mut Dice) -> T) -> SimutronResult<T> {
+        if self.get_resource::<Dice>().is_none() {
+            self.seed_dice(Uuid::new_v4().as_u64_pair().0);
+        }
+        match self.get_resource_mut::<Dice>() {
+            Some(dice) => Ok(f(dice)),
+            None => runtime_error!("World has no dice."),
+        }
+    }
+}
@@ -20,6 +20,9 @@ pub struct Position {
     pub map: Option<Uuid>,
     pub x: u32,
     pub y: u32,
+    /// The elevation of the entity, in the same unitless measure as the map scale.
+    /// Creatures standing on the ground share the elevation of the tile beneath them.
+    pub z: u32,
 }
 
 impl Component for Position {}
@@ -32,6 +35,11 @@ pub struct Inventory {
     pub items: Vec<Uuid>,
 }
 impl Component for Inventory {}
+impl Default for Inventory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 impl Inventory {
     pub fn new() -> Self {
         Self { items: Vec::new() }
@@ -6,4 +6,7 @@ pub mod component;
 /// Components are a series of structs (which are of the component trait).
 /// You can use components to build useful entities.
 pub mod components;
+
+/// Resources are world-wide singletons which are not attached to any entity.
+pub mod resource;
 pub mod world;
@@ -0,0 +1,6 @@
+use std::any::Any;
+use std::fmt;
+
+/// A resource is a world-wide singleton such as rules, clocks or random number generators.
+/// Unlike components, resources do not belong to any entity and there is at most one of each type.
+pub trait Resource: Any + Send + Sync + fmt::Debug {}
@@ -1,21 +1,42 @@
-use crate::creatures::components::CreatureSheet;
+use crate::creatures::components::{CreatureActions, CreatureSheet, MovementBudget, MovementModes};
+use crate::creatures::creature_builder::{AppendageEffect, Capability, Health};
 use crate::creatures::Creature;
 use crate::ecs::component::{Component, ComponentVec};
 use crate::ecs::components::{Inventory, Position, PropHealth};
 use crate::ecs::entity::Entity;
+use crate::ecs::resource::Resource;
 use crate::errors::{SimutronError, SimutronResult};
-use crate::map::base_terrain::{BaseMap, Map, Terrain};
+use crate::map::base_terrain::{BaseMap, Maneuverability, Map, MovementMode, Terrain};
+use crate::map::elevation::VerticalMovement;
+use crate::map::movement_report::{MovementReport, MovementStep, StopReason};
+use crate::map::movement_rules::MovementRules;
 use crate::props::components::Prop;
 use crate::runtime_error;
 use log::warn;
-use std::any::TypeId;
+use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use uuid::Uuid;
 
+/// The price of a single step between two adjacent tiles.
+pub(crate) struct StepCost {
+    /// Movement spent to take the step.
+    pub(crate) cost: u32,
+    /// Whether the step cuts across a corner of a square grid.
+    pub(crate) diagonal: bool,
+    /// The health impact of landing, if the step is a fall.
+    pub(crate) fall_damage: Option<Health>,
+    /// Where the creature ends up, standing on the destination tile.
+    pub(crate) destination: Position,
+    /// The mode the step is really taken in. A swimmer climbing out onto the bank walks the last step.
+    pub(crate) mode: MovementMode,
+}
+
 // The World holds all entities and components
 pub struct World {
     // next_entity_id: u64,
     components: HashMap<TypeId, ComponentVec>,
+    // World-wide singletons, one per type.
+    resources: HashMap<TypeId, Box<dyn Any>>,
     maps: HashMap<Uuid, Box<dyn Map>>,
     // A lookup table for creature names to their entity IDs allows for O(1) retrieval
     creature_lookup: HashMap<String, Uuid>,
@@ -34,6 +55,7 @@ impl World {
         Self {
             // next_entity_id: 0,
             components: HashMap::new(),
+            resources: HashMap::new(),
             maps: HashMap::new(),
             creature_lookup: HashMap::new(),
             position_lookup: HashMap::new(),
@@ -54,24 +76,70 @@ impl World {
         self.maps.insert(map.id, Box::new(map));
     }
 
+    pub(crate) fn get_map(&self, map_id: &Uuid) -> Option<&dyn Map> {
+        self.maps.get(map_id).map(|map| map.as_ref())
+    }
+
+    /// Returns every entity standing at the given position.
+    /// Only positions set through the world's movement methods are tracked.
+    pub fn get_entities_at(&self, position: &Position) -> Vec<Entity> {
+        self.position_lookup
+            .get(position)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Moves an entity's Position component and keeps the spatial lookup in sync.
+    pub(crate) fn set_position(&mut self, entity: Entity, position: Position) {
+        if let Some(previous) = self.get_component::<Position>(entity).copied()
+            && let Some(occupants) = self.position_lookup.get_mut(&previous)
+        {
+            occupants.retain(|occupant| *occupant != entity);
+            if occupants.is_empty() {
+                self.position_lookup.remove(&previous);
+            }
+        }
+        self.position_lookup
+            .entry(position)
+            .or_default()
+            .push(entity);
+        self.add_component(entity, position);
+    }
+
     // Create a new entity
     pub fn create_entity(&mut self) -> Entity {
         let new_id = Uuid::new_v4();
         Entity(new_id)
     }
 
+    /// Removes an entity and all of its components from the world.
+    pub fn despawn_entity(&mut self, entity: Entity) {
+        if let Some(position) = self.get_component::<Position>(entity).copied()
+            && let Some(occupants) = self.position_lookup.get_mut(&position)
+        {
+            occupants.retain(|occupant| *occupant != entity);
+            if occupants.is_empty() {
+                self.position_lookup.remove(&position);
+            }
+        }
+        self.creature_lookup.retain(|_, uuid| *uuid != entity.0);
+        for storage in self.components.values_mut() {
+            storage.remove(entity.0);
+        }
+    }
+
     /// Teleport a creature to a new position without any movement rules.
     /// Use this sparingly, as it bypasses all movement constraints.
     /// Mainly useful for fast travel, respawning, or debugging.
+    /// The creature lands on the ground, so the requested elevation is replaced by the tile's elevation.
     pub fn teleport_creature(
         &mut self,
         creature: Entity,
         new_position: Position,
     ) -> SimutronResult<Entity> {
-        let _creature_component = match self.get_component::<Creature>(creature) {
-            Some(c) => c.clone(),
-            None => return runtime_error!("Entity is not a creature."),
-        };
+        if self.get_component::<Creature>(creature).is_none() {
+            return runtime_error!("Entity is not a creature.");
+        }
         // validate that the new position is within map bounds
         let map_id = match &new_position.map {
             Some(id) => id,
@@ -86,124 +154,352 @@ impl World {
         if new_position.x >= map_width || new_position.y >= map_height {
             return runtime_error!("New position is out of map bounds.");
         }
+        let elevation = match map.get_elevation(new_position) {
+            Some(elevation) => elevation,
+            None => return runtime_error!("Could not get elevation for new position."),
+        };
 
-        self.add_component(creature, new_position);
+        self.set_position(
+            creature,
+            Position {
+                z: elevation,
+                ..new_position
+            },
+        );
         Ok(creature)
     }
 
     // A method to move a creature to a new position.
     // Input: Creature entity, movement requests as a vector. Each tile must be adjacent to the previous tile. The movement of all tiles must be less than or equal to the creature's speed stat.
-    // Output: Result with updated Creature or error. Also mutate the char's position component.
+    // Output: Result with a report of the movement actually made, or error. Also mutate the char's position component.
     // Constraints: The new position must be valid within the map bounds. The new position is valid ONLY if it adheres to the movement rules.
     // Edge: Must calculate diagonal movement correctly.
     pub fn move_creature(
         &mut self,
         creature: Entity,
         requested_positions: Vec<Position>,
-    ) -> SimutronResult<Entity> {
+    ) -> SimutronResult<MovementReport> {
         // Movement rules:
         // 1. A creature can move the space less than or equal to its speed stat considering the tile size of a map.
         // 2. When moving diagonally, the creature moves at 1.4x speed cost.
         // 3. All requested positions must be adjacent to the previous position.
+        // 4. Creatures walk on the ground. The requested elevation is ignored and replaced by the tile's elevation.
+        // 5. A creature stops short at the edge of the map, at blocking terrain, or at a tile another creature stands on.
         // Is the entity a creature?
-        let creature_component = match self.get_component::<Creature>(creature) {
-            Some(c) => c.clone(),
-            None => return runtime_error!("Entity is not a creature."),
-        };
+        if self.get_component::<Creature>(creature).is_none() {
+            return runtime_error!("Entity is not a creature.");
+        }
         // get the current position
         let starting_position = match self.get_component::<Position>(creature) {
-            Some(pos) => pos.clone(),
+            Some(pos) => *pos,
             None => return runtime_error!("Creature has no position component."),
         };
-        let creature_sheet = match self.get_component::<CreatureSheet>(creature) {
-            Some(sheet) => sheet.clone(),
-            None => return runtime_error!("Creature has no Creature Sheet component."),
-        };
+        let speeds = self.get_movement_speeds(creature)?;
+        // Outside an encounter there are no turns, so every move gets the creature's full speed.
+        // In an encounter, moves spend from the budget of the current turn.
+        let available_movement = self.get_remaining_movement(creature)?;
 
-        let position = self.calculate_final_position(
+        let report = self.calculate_final_position(
+            creature,
             requested_positions,
-            creature_sheet.speed,
+            &speeds,
+            available_movement,
             starting_position,
+            0,
         )?;
+        if !report.is_complete() && !self.get_movement_rules().allow_partial_moves {
+            return runtime_error!(
+                "Cannot complete the requested path: {:?}.",
+                report.stop_reason
+            );
+        }
+
         // Update the creature's position component
-        self.add_component(creature, position);
+        if let Some(position) = report.get_final_position() {
+            self.set_position(creature, position);
+        }
+        if self.is_in_encounter(creature) {
+            match self.get_component_mut::<MovementBudget>(creature) {
+                Some(budget) => budget.spend(report.spent),
+                None => {
+                    let mut budget = MovementBudget::new();
+                    budget.spend(report.spent);
+                    self.add_component(creature, budget);
+                }
+            }
+        }
 
-        Ok(creature)
+        self.apply_fall_damage(&report)?;
+
+        Ok(report)
     }
 
-    fn calculate_final_position(
-        &mut self,
+    /// Landing after a long drop hurts. The blow lands on the root appendage, e.g. a humanoid's torso.
+    /// Falls stop hurting once a landing turned the creature into a corpse.
+    pub(crate) fn apply_fall_damage(&mut self, report: &MovementReport) -> SimutronResult<()> {
+        let root = match self.get_component::<Creature>(report.creature) {
+            Some(creature) => creature.corpus.name.clone(),
+            None => return runtime_error!("Entity is not a creature."),
+        };
+        for impact in report.steps.iter().filter_map(|step| step.fall_damage) {
+            if self.get_component::<Creature>(report.creature).is_none() {
+                break;
+            }
+            let fall = CreatureActions {
+                from: report.creature,
+                to: report.creature,
+                target: root.clone(),
+                effect: AppendageEffect::Abrasion,
+                impact,
+            };
+            self.apply_creature_action(&fall)?;
+        }
+        Ok(())
+    }
+
+    /// Walks the requested path as far as the creature can go and reports every step taken.
+    /// Every step is taken in whichever of the creature's movement modes is cheapest.
+    /// Nothing is mutated, so the report can be thrown away if the move is rejected.
+    /// `diagonals_taken` carries the diagonal count over from an earlier leg of the same path.
+    pub(crate) fn calculate_final_position(
+        &self,
+        creature: Entity,
         requested_positions: Vec<Position>,
+        speeds: &[(MovementMode, u32)],
         available_movement: u32,
         init_position: Position,
-    ) -> SimutronResult<Position> {
+        diagonals_taken: u32,
+    ) -> SimutronResult<MovementReport> {
+        // The fastest mode sets the movement budget. Slower modes pay proportionally more for each step.
+        let budget = Self::get_budget_speed(speeds);
+        let mut report = MovementReport {
+            creature,
+            steps: Vec::new(),
+            spent: 0,
+            remaining: available_movement,
+            stop_reason: StopReason::Completed,
+        };
         let mut init_position = init_position;
-        let mut available_movement = available_movement;
-        for (index, final_position) in requested_positions.iter().enumerate() {
-            // Check adjacency
-            let dx = (final_position.x as i32 - init_position.x as i32).abs();
-            let dy = (final_position.y as i32 - init_position.y as i32).abs();
-            if dx > 1 || dy > 1 || (dx == 0 && dy == 0) {
-                return runtime_error!("Requested position is not adjacent to previous position.");
+        let mut diagonals_taken = diagonals_taken;
+        for final_position in requested_positions.iter() {
+            if self.is_off_map(final_position) {
+                report.stop_reason = StopReason::MapEdge;
+                break;
+            }
+            if self.is_occupied(final_position, creature) {
+                report.stop_reason = StopReason::Occupied;
+                break;
             }
-            // TODO: Memoize the map scale if the map is the same as the last loop.
-            // MOVEMENT MODIFIERS ARE HERE
-            let current_map_scale = match &final_position.map {
-                Some(map_id) => {
-                    let map = match self.maps.get(map_id) {
-                        Some(m) => m,
-                        None => {
-                            return runtime_error!("Map not found for position during movement.");
-                        }
+
+            let mut best_step: Option<StepCost> = None;
+            for (mode, _) in speeds.iter().filter(|(_, speed)| *speed > 0) {
+                if let Some(step) =
+                    self.get_step_cost(init_position, *final_position, diagonals_taken, *mode)?
+                {
+                    // The step is paid for at the speed of the mode it is really taken in.
+                    let speed = match speeds
+                        .iter()
+                        .find(|(speed_mode, _)| *speed_mode == step.mode)
+                    {
+                        Some((_, speed)) if *speed > 0 => *speed,
+                        _ => continue,
                     };
-                    map.get_scale()
-                }
-                None => return runtime_error!("No position no position map requested."),
-            };
-            let maneuver_modifier = match &final_position.map {
-                Some(map_id) => match self.maps.get(map_id) {
-                    Some(map) => match map.get_maneuverability(init_position) {
-                        Some(m) => m.get_modifier() as u32,
-                        None => {
-                            return runtime_error!(
-                                "Could not get maneuverability for position during movement."
-                            );
-                        }
-                    },
-                    None => {
-                        return runtime_error!("Map not found for position during movement.");
+                    let cost = step.cost.saturating_mul(budget).div_ceil(speed);
+                    if best_step.as_ref().is_none_or(|best| cost < best.cost) {
+                        best_step = Some(StepCost { cost, ..step });
                     }
-                },
-                None => return runtime_error!("No position no position map requested."),
-            };
-
-            // First diagonal move costs 1 square, the second diagonal move costs 2 squares, then it repeats.
-            // Therefore: if even 1, if odd cost * 2
-            let base_cost = if dx == 1 && dy == 1 {
-                if (index & 1) == 0 {
-                    current_map_scale
-                } else {
-                    // Scale^2
-                    current_map_scale << 1
                 }
-            } else {
-                current_map_scale // Orthogonal movement is the cost
+            }
+            let step = match best_step {
+                Some(best) => best,
+                None => {
+                    report.stop_reason = StopReason::Blocked;
+                    break;
+                }
             };
+            if report.remaining < step.cost {
+                // Not enough movement left to proceed to the next position
+                report.stop_reason = StopReason::OutOfMovement;
+                break;
+            }
 
-            let total_cost = base_cost * maneuver_modifier;
+            report.remaining -= step.cost;
+            report.spent += step.cost;
+            if step.diagonal {
+                diagonals_taken += 1;
+            }
+            report.steps.push(MovementStep {
+                position: step.destination,
+                cost: step.cost,
+                mode: step.mode,
+                diagonal: step.diagonal,
+                fall_damage: step.fall_damage,
+            });
+            init_position = step.destination;
+        }
+        Ok(report)
+    }
 
-            if available_movement >= total_cost {
-                available_movement -= total_cost;
-                init_position = *final_position;
-            } else {
-                // Not enough movement left to proceed to the next position
-                // TODO: Verify that this is the correct position to return, may need to return the previous position instead
-                return Ok(init_position.to_owned());
+    /// Returns the speed of every way the creature can move.
+    /// Creatures walk at their sheet speed, unless they know better.
+    /// Every lost or disabled locomotion appendage slows down all modes but flying.
+    pub(crate) fn get_movement_speeds(
+        &self,
+        creature: Entity,
+    ) -> SimutronResult<Vec<(MovementMode, u32)>> {
+        let creature_sheet = match self.get_component::<CreatureSheet>(creature) {
+            Some(sheet) => sheet,
+            None => return runtime_error!("Creature has no Creature Sheet component."),
+        };
+        let mut speeds = vec![(MovementMode::Walk, creature_sheet.speed)];
+        if let Some(modes) = self.get_component::<MovementModes>(creature) {
+            speeds.retain(|(mode, _)| !modes.speeds.contains_key(mode));
+            speeds.extend(modes.speeds.iter().map(|(mode, speed)| (*mode, *speed)));
+            speeds.sort();
+        }
+        let locomotion = self
+            .get_component::<Creature>(creature)
+            .map_or(1.0, |creature| {
+                creature.get_capability_ratio(Capability::Locomotion)
+            });
+        for (mode, speed) in speeds.iter_mut() {
+            if *mode != MovementMode::Fly {
+                *speed = (*speed as f64 * locomotion) as u32;
             }
         }
-        // get the last position in the requested positions
-        let last_position = *requested_positions.last().unwrap();
-        Ok(last_position.to_owned())
+        Ok(speeds)
+    }
+
+    /// The fastest movement mode sets how much movement a creature gets per turn.
+    pub(crate) fn get_budget_speed(speeds: &[(MovementMode, u32)]) -> u32 {
+        speeds.iter().map(|(_, speed)| *speed).max().unwrap_or(0)
+    }
+
+    /// Returns true if the position is on a known map but outside of its bounds.
+    pub(crate) fn is_off_map(&self, position: &Position) -> bool {
+        match position.map.and_then(|map_id| self.maps.get(&map_id)) {
+            Some(map) => position.x >= map.get_width() || position.y >= map.get_height(),
+            None => false,
+        }
+    }
+
+    /// Returns true if a creature other than `mover` stands on the tile.
+    /// Creatures stand on the ground, so the tile's elevation is looked up rather than the requested one.
+    fn is_occupied(&self, position: &Position, mover: Entity) -> bool {
+        let ground = position
+            .map
+            .and_then(|map_id| self.maps.get(&map_id))
+            .and_then(|map| map.get_elevation(*position))
+            .map_or(*position, |elevation| Position {
+                z: elevation,
+                ..*position
+            });
+        self.position_lookup.get(&ground).is_some_and(|occupants| {
+            occupants.iter().any(|occupant| {
+                *occupant != mover && self.get_component::<Creature>(*occupant).is_some()
+            })
+        })
+    }
+
+    /// Returns the movement rules inserted on the world, or the default rules.
+    pub(crate) fn get_movement_rules(&self) -> MovementRules {
+        self.get_resource::<MovementRules>()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Works out what a single step between two adjacent tiles costs in the given movement mode.
+    /// `diagonals_taken` is the number of diagonal steps already made along the same path.
+    /// Returns None if the mode cannot take the step at all.
+    pub(crate) fn get_step_cost(
+        &self,
+        from: Position,
+        to: Position,
+        diagonals_taken: u32,
+        mode: MovementMode,
+    ) -> SimutronResult<Option<StepCost>> {
+        // TODO: Memoize the map scale if the map is the same as the last loop.
+        // MOVEMENT MODIFIERS ARE HERE
+        let map = match &to.map {
+            Some(map_id) => match self.maps.get(map_id) {
+                Some(m) => m,
+                None => {
+                    return runtime_error!("Map not found for position during movement.");
+                }
+            },
+            None => return runtime_error!("No position no position map requested."),
+        };
+        // Check adjacency
+        let topology = map.get_topology();
+        if !topology.is_adjacent((from.x, from.y), (to.x, to.y)) {
+            return runtime_error!("Requested position is not adjacent to previous position.");
+        }
+        let current_map_scale = map.get_scale();
+        let rules = self.get_movement_rules();
+        let (leaving, entering) = match (map.get_maneuverability(from), map.get_maneuverability(to))
+        {
+            (Some(leaving), Some(entering)) => (leaving, entering),
+            _ => {
+                return runtime_error!(
+                    "Could not get maneuverability for position during movement."
+                );
+            }
+        };
+        let leaving_native = map.get_movement_mode(from).unwrap_or(MovementMode::Walk);
+        let entering_native = map.get_movement_mode(to).unwrap_or(MovementMode::Walk);
+        // A creature may swim out of a stream or climb down off rocks onto open ground, and walks that step.
+        // It cannot use its mode to get into any other terrain the mode does not work in.
+        let (maneuver_modifier, step_mode) = match (
+            mode.get_tile_modifier(leaving_native, &leaving),
+            mode.get_tile_modifier(entering_native, &entering),
+        ) {
+            (None, None) => return Ok(None),
+            (Some(_), None)
+                if entering_native != MovementMode::Walk
+                    || entering == Maneuverability::Blocking =>
+            {
+                return Ok(None);
+            }
+            (Some(leaving_modifier), None) => (
+                rules
+                    .terrain
+                    .get_modifier(leaving_modifier, entering.get_modifier() as u32),
+                MovementMode::Walk,
+            ),
+            (leaving_modifier, entering_modifier) => (
+                rules.terrain.get_modifier(
+                    leaving_modifier.unwrap_or(leaving.get_modifier() as u32),
+                    entering_modifier.unwrap_or(entering.get_modifier() as u32),
+                ),
+                mode,
+            ),
+        };
+        let elevation = match map.get_elevation(to) {
+            Some(e) => e,
+            None => {
+                return runtime_error!("Could not get elevation for position during movement.");
+            }
+        };
+
+        // By default the first diagonal move costs 1 square, the second diagonal move costs 2 squares, then it repeats.
+        let diagonal = topology.is_diagonal((from.x, from.y), (to.x, to.y));
+        let base_cost = if diagonal {
+            rules
+                .diagonals
+                .get_diagonal_cost(current_map_scale, diagonals_taken)
+        } else {
+            current_map_scale // Orthogonal movement is the cost
+        };
+
+        // Climbing up costs extra, dropping down is free but may hurt on landing.
+        let vertical = VerticalMovement::between(from.z, elevation, current_map_scale);
+        Ok(Some(StepCost {
+            cost: base_cost * maneuver_modifier + vertical.get_cost(mode),
+            diagonal,
+            fall_damage: vertical.get_fall_damage(mode, current_map_scale),
+            destination: Position { z: elevation, ..to },
+            mode: step_mode,
+        }))
     }
 
     pub fn create_creature(&mut self, creature: Creature) -> Entity {
@@ -225,12 +521,13 @@ impl World {
                 charisma: 0,
             },
         );
-        self.add_component(
+        self.set_position(
             new_entity,
             Position {
                 map: None,
                 x: 0,
                 y: 0,
+                z: 0,
             },
         );
         new_entity
@@ -242,12 +539,13 @@ impl World {
         self.add_component(new_entity, new_prop);
         self.add_component(new_entity, PropHealth::new(100));
         self.add_component(new_entity, Inventory::new());
-        self.add_component(
+        self.set_position(
             new_entity,
             Position {
                 map: None,
                 x: 0,
                 y: 0,
+                z: 0,
             },
         );
         new_entity
@@ -281,6 +579,16 @@ impl World {
             .downcast_mut::<T>()
     }
 
+    // Get every entity that has a component of the given type, in a stable order
+    pub fn get_entities_with<T: Component>(&self) -> Vec<Entity> {
+        let mut entities: Vec<Entity> = match self.components.get(&TypeId::of::<T>()) {
+            Some(storage) => storage.data.keys().map(|uuid| Entity(*uuid)).collect(),
+            None => Vec::new(),
+        };
+        entities.sort_by_key(|entity| entity.get_uuid());
+        entities
+    }
+
     // Remove a component from an entity
     pub fn remove_component<T: Component>(&mut self, entity: Entity) {
         let type_id = TypeId::of::<T>();
@@ -288,4 +596,30 @@ impl World {
             storage.remove(entity.get_uuid());
         }
     }
+
+    // Insert a resource, replacing any existing resource of the same type
+    pub fn insert_resource<R: Resource>(&mut self, resource: R) {
+        self.resources.insert(TypeId::of::<R>(), Box::new(resource));
+    }
+
+    // Get an immutable reference to a resource
+    pub fn get_resource<R: Resource>(&self) -> Option<&R> {
+        self.resources.get(&TypeId::of::<R>())?.downcast_ref::<R>()
+    }
+
+    // Get a mutable reference to a resource
+    pub fn get_resource_mut<R: Resource>(&mut self) -> Option<&mut R> {
+        self.resources
+            .get_mut(&TypeId::of::<R>())?
+            .downcast_mut::<R>()
+    }
+
+    // Remove a resource from the world, handing it back
+    pub fn remove_resource<R: Resource>(&mut self) -> Option<R> {
+        self.resources
+            .remove(&TypeId::of::<R>())?
+            .downcast::<R>()
+            .ok()
+            .map(|resource| *resource)
+    }
 }
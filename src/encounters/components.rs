@@ -0,0 +1,109 @@
+use crate::ecs::entity::Entity;
+use crate::ecs::resource::Resource;
+
+/// A creature taking part in an encounter along with its place in the turn order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Combatant {
+    pub entity: Entity,
+    pub initiative: i32,
+}
+
+/// The turn order of a running encounter. Lives on the World as a resource while the encounter runs.
+#[derive(Debug, Clone)]
+pub struct Encounter {
+    /// Participants sorted by initiative, highest first.
+    pub(crate) order: Vec<Combatant>,
+    /// Index into `order` of whoever is acting.
+    pub(crate) current: usize,
+    /// Rounds start at 1 and go up every time the turn order wraps around.
+    pub(crate) round: u32,
+    /// The last round the world's clock was moved forward for.
+    pub(crate) clock_round: u32,
+}
+
+impl Resource for Encounter {}
+
+impl Default for Encounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Encounter {
+    pub fn new() -> Self {
+        Self {
+            order: Vec::new(),
+            current: 0,
+            round: 1,
+            clock_round: 1,
+        }
+    }
+
+    pub fn get_round(&self) -> u32 {
+        self.round
+    }
+
+    /// Returns the participants in the order they act.
+    pub fn get_turn_order(&self) -> Vec<Combatant> {
+        self.order.clone()
+    }
+
+    /// Returns the participant whose turn it is.
+    pub fn current_actor(&self) -> Option<Entity> {
+        self.order
+            .get(self.current)
+            .map(|combatant| combatant.entity)
+    }
+
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.order
+            .iter()
+            .any(|combatant| combatant.entity == entity)
+    }
+
+    /// Slots a participant into the turn order without disturbing whoever is acting.
+    /// Ties go to whoever joined first.
+    pub(crate) fn insert(&mut self, combatant: Combatant) {
+        let index = self
+            .order
+            .iter()
+            .position(|other| other.initiative < combatant.initiative)
+            .unwrap_or(self.order.len());
+        if index <= self.current && !self.order.is_empty() {
+            self.current += 1;
+        }
+        self.order.insert(index, combatant);
+    }
+
+    /// Takes a participant out of the turn order. If it was acting, the next participant is up.
+    pub(crate) fn remove(&mut self, entity: Entity) -> Option<Combatant> {
+        let index = self
+            .order
+            .iter()
+            .position(|combatant| combatant.entity == entity)?;
+        let combatant = self.order.remove(index);
+        if index < self.current {
+            self.current -= 1;
+        }
+        if self.current >= self.order.len() {
+            // The last participant left on their own turn, so the round is over.
+            self.current = 0;
+            if !self.order.is_empty() {
+                self.round += 1;
+            }
+        }
+        Some(combatant)
+    }
+
+    /// Moves on to the next participant, wrapping around into a new round.
+    pub(crate) fn advance(&mut self) {
+        if self.order.is_empty() {
+            return;
+        }
+        self.current += 1;
+        if self.current >= self.order.len() {
+            self.current = 0;
+            self.round += 1;
+        }
+    }
+}
@@ -0,0 +1,4 @@
+/// Encounters run tabletop style turn-based play.
+/// Participants roll initiative, act one at a time in order, and the encounter counts the rounds.
+pub mod components;
+pub(crate) mod systems;
@@ -0,0 +1,217 @@
+use crate::creatures::components::{CreatureSheet, VitalState};
+use crate::creatures::Creature;
+use crate::ecs::entity::Entity;
+use crate::ecs::world::World;
+use crate::encounters::components::{Combatant, Encounter};
+use crate::errors::{SimutronError, SimutronResult};
+use crate::runtime_error;
+use log::debug;
+
+impl World {
+    /// Starts a new encounter, rolling initiative for every participant.
+    /// Any encounter already running is replaced. Returns whoever acts first.
+    pub fn start_encounter(&mut self, participants: &[Entity]) -> SimutronResult<Option<Entity>> {
+        let mut seats = Vec::new();
+        for participant in participants {
+            seats.push((*participant, self.roll_initiative(*participant)?));
+        }
+        self.start_encounter_with_initiative(&seats)
+    }
+
+    /// Starts a new encounter with initiatives decided elsewhere, e.g. rolled at the table.
+    /// Any encounter already running is replaced. Returns whoever acts first.
+    pub fn start_encounter_with_initiative(
+        &mut self,
+        participants: &[(Entity, i32)],
+    ) -> SimutronResult<Option<Entity>> {
+        let mut encounter = Encounter::new();
+        for (participant, initiative) in participants {
+            if self.get_component::<Creature>(*participant).is_none() {
+                return runtime_error!("Entity {:?} is not a creature.", participant);
+            }
+            if encounter.contains(*participant) {
+                return runtime_error!("Creature {:?} is already in the encounter.", participant);
+            }
+            encounter.insert(Combatant {
+                entity: *participant,
+                initiative: *initiative,
+            });
+        }
+        // Nobody has acted yet, so the highest initiative goes first.
+        encounter.current = 0;
+        self.insert_resource(encounter);
+        self.begin_turn()
+    }
+
+    /// Ends the running encounter and hands it back.
+    pub fn end_encounter(&mut self) -> Option<Encounter> {
+        self.remove_resource::<Encounter>()
+    }
+
+    /// Returns the creature whose turn it is, if an encounter is running.
+    pub fn current_actor(&self) -> Option<Entity> {
+        self.get_resource::<Encounter>()?.current_actor()
+    }
+
+    /// Whether the creature takes part in the running encounter.
+    pub fn is_in_encounter(&self, creature: Entity) -> bool {
+        self.get_resource::<Encounter>()
+            .is_some_and(|encounter| encounter.contains(creature))
+    }
+
+    /// Ends the current actor's turn and starts the next one.
+    /// Participants who died or were removed from the world are dropped from the turn order along the way.
+    /// Returns whoever acts next.
+    pub fn end_turn(&mut self) -> SimutronResult<Option<Entity>> {
+        match self.get_resource_mut::<Encounter>() {
+            Some(encounter) => encounter.advance(),
+            None => return runtime_error!("No encounter is running."),
+        }
+        self.begin_turn()
+    }
+
+    /// Rolls initiative for a creature and adds it to the running encounter.
+    pub fn join_encounter(&mut self, creature: Entity) -> SimutronResult<i32> {
+        let initiative = self.roll_initiative(creature)?;
+        self.join_encounter_with_initiative(creature, initiative)?;
+        Ok(initiative)
+    }
+
+    /// Adds a creature to the running encounter with a set initiative, e.g. one rolled at the table.
+    pub fn join_encounter_with_initiative(
+        &mut self,
+        creature: Entity,
+        initiative: i32,
+    ) -> SimutronResult<()> {
+        if self.get_component::<Creature>(creature).is_none() {
+            return runtime_error!("Entity {:?} is not a creature.", creature);
+        }
+        let was_empty = match self.get_resource_mut::<Encounter>() {
+            Some(encounter) if encounter.contains(creature) => {
+                return runtime_error!("Creature {:?} is already in the encounter.", creature);
+            }
+            Some(encounter) => {
+                let was_empty = encounter.order.is_empty();
+                encounter.insert(Combatant {
+                    entity: creature,
+                    initiative,
+                });
+                was_empty
+            }
+            None => return runtime_error!("No encounter is running."),
+        };
+        if was_empty {
+            self.begin_turn()?;
+        }
+        Ok(())
+    }
+
+    /// Removes a creature from the running encounter. If it was acting, the next participant's turn starts.
+    pub fn leave_encounter(&mut self, creature: Entity) -> SimutronResult<Option<Entity>> {
+        let was_acting = match self.get_resource_mut::<Encounter>() {
+            Some(encounter) => {
+                let was_acting = encounter.current_actor() == Some(creature);
+                if encounter.remove(creature).is_none() {
+                    return runtime_error!("Creature {:?} is not in the encounter.", creature);
+                }
+                was_acting
+            }
+            None => return runtime_error!("No encounter is running."),
+        };
+        if was_acting {
+            self.begin_turn()
+        } else {
+            Ok(self.current_actor())
+        }
+    }
+
+    /// Starts the current actor's turn. The dead and the despawned are dropped from the turn order along the way.
+    /// Stunned, unconscious and dying participants lose their turn but stay in the encounter.
+    /// Returns None if nobody left in the encounter can act.
+    /// Every finished round moves the world's clock forward by one turn and ticks it once.
+    /// Real-time movement orders and action timers stay paused while the encounter runs.
+    fn begin_turn(&mut self) -> SimutronResult<Option<Entity>> {
+        // Once a whole round went by without time passing, stuns will not wear off, so stop skipping.
+        let mut stunned_skips = 0;
+        loop {
+            let finished_round = match self.get_resource_mut::<Encounter>() {
+                Some(encounter) if encounter.clock_round < encounter.round => {
+                    encounter.clock_round += 1;
+                    true
+                }
+                _ => false,
+            };
+            if finished_round {
+                // A round lasts one turn of game time, but only periodic effects run during it.
+                let mut clock = self.get_clock();
+                clock.elapsed += clock.turn_length;
+                self.insert_resource(clock);
+                self.tick()?;
+                stunned_skips = 0;
+                continue;
+            }
+            let actor = match self.current_actor() {
+                Some(actor) => actor,
+                None => return Ok(None),
+            };
+            let state = self
+                .get_component::<Creature>(actor)
+                .map(|creature| creature.get_vital_state());
+            if state.is_none_or(|state| state == VitalState::Dead) {
+                debug!("{:?} is gone and leaves the encounter", actor);
+                if let Some(encounter) = self.get_resource_mut::<Encounter>() {
+                    encounter.remove(actor);
+                }
+                continue;
+            }
+            if state != Some(VitalState::Alive) {
+                // The unconscious and the dying keep their place in case they come round.
+                if !self.anyone_can_act() {
+                    return Ok(None);
+                }
+                debug!("{:?} cannot act and loses its turn", actor);
+                if let Some(encounter) = self.get_resource_mut::<Encounter>() {
+                    encounter.advance();
+                }
+                continue;
+            }
+            let participants = self
+                .get_resource::<Encounter>()
+                .map_or(0, |encounter| encounter.order.len());
+            if self.is_stunned(actor) && stunned_skips < participants {
+                debug!("{:?} is stunned and loses its turn", actor);
+                stunned_skips += 1;
+                if let Some(encounter) = self.get_resource_mut::<Encounter>() {
+                    encounter.advance();
+                }
+                continue;
+            }
+            self.reset_movement_budget(actor)?;
+            debug!("Turn starts for {:?}", actor);
+            return Ok(Some(actor));
+        }
+    }
+
+    /// Whether any participant of the running encounter is conscious.
+    fn anyone_can_act(&self) -> bool {
+        self.get_resource::<Encounter>().is_some_and(|encounter| {
+            encounter.order.iter().any(|combatant| {
+                self.get_component::<Creature>(combatant.entity)
+                    .is_some_and(|creature| creature.can_act())
+            })
+        })
+    }
+
+    /// Rolls a d20 with the world's dice and adds the creature's dexterity modifier.
+    fn roll_initiative(&mut self, creature: Entity) -> SimutronResult<i32> {
+        if self.get_component::<Creature>(creature).is_none() {
+            return runtime_error!("Entity {:?} is not a creature.", creature);
+        }
+        let dexterity = match self.get_component::<CreatureSheet>(creature) {
+            Some(sheet) => sheet.dexterity,
+            None => return runtime_error!("Creature has no Creature Sheet component."),
+        };
+        let roll = self.roll("1d20")?;
+        Ok(roll.total + CreatureSheet::get_modifier(dexterity))
+    }
+}
@@ -0,0 +1,83 @@
+use crate::creatures::creature_builder::{AppendageId, Health};
+use crate::ecs::component::Component;
+use crate::ecs::entity::Entity;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// A place on an appendage where something can be worn or wielded. Each appendage holds one item per slot.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EquipmentSlot {
+    Helmet,
+    /// Body armor, worn on the torso.
+    Armor,
+    Weapon,
+    Shield,
+    Gloves,
+    Boots,
+}
+
+/// Makes a prop wearable. Lives on the prop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Equippable {
+    pub slot: EquipmentSlot,
+    /// Damage soaked up by every cut, crush and burn to the appendage wearing it, on top of the appendage's own armor.
+    pub armor: Health,
+}
+impl Component for Equippable {}
+
+/// What a creature is wearing and wielding, by appendage and slot.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Equipment {
+    items: BTreeMap<(AppendageId, EquipmentSlot), Entity>,
+}
+impl Component for Equipment {}
+
+impl Equipment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, appendage: AppendageId, slot: EquipmentSlot) -> Option<Entity> {
+        self.items.get(&(appendage, slot)).copied()
+    }
+
+    /// Returns everything equipped, ordered by appendage and slot.
+    pub fn get_items(&self) -> impl Iterator<Item = (AppendageId, EquipmentSlot, Entity)> + '_ {
+        self.items
+            .iter()
+            .map(|((appendage, slot), item)| (*appendage, *slot, *item))
+    }
+
+    /// Returns everything equipped on one appendage.
+    pub fn get_items_on(&self, appendage: AppendageId) -> impl Iterator<Item = Entity> + '_ {
+        self.get_items()
+            .filter(move |(id, _, _)| *id == appendage)
+            .map(|(_, _, item)| item)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub(crate) fn insert(&mut self, appendage: AppendageId, slot: EquipmentSlot, item: Entity) {
+        self.items.insert((appendage, slot), item);
+    }
+
+    pub(crate) fn remove(&mut self, appendage: AppendageId, slot: EquipmentSlot) -> Option<Entity> {
+        self.items.remove(&(appendage, slot))
+    }
+
+    /// Takes everything off the appendage.
+    pub(crate) fn remove_from(&mut self, appendage: AppendageId) -> Vec<Entity> {
+        let slots: Vec<_> = self
+            .items
+            .keys()
+            .filter(|(id, _)| *id == appendage)
+            .copied()
+            .collect();
+        slots
+            .into_iter()
+            .filter_map(|key| self.items.remove(&key))
+            .collect()
+    }
+}
@@ -0,0 +1,4 @@
+/// Equipment. Appendages expose slots for helmets, armor, weapons and the like, and props worn in them
+/// protect the appendage from cuts, crushing and burns.
+pub mod components;
+pub(crate) mod systems;
@@ -0,0 +1,120 @@
+use crate::creatures::creature_builder::{AppendageId, Health};
+use crate::creatures::Creature;
+use crate::ecs::components::Inventory;
+use crate::ecs::entity::Entity;
+use crate::ecs::world::World;
+use crate::equipment::components::{Equipment, EquipmentSlot, Equippable};
+use crate::errors::{SimutronError, SimutronResult};
+use crate::runtime_error;
+use log::debug;
+
+impl World {
+    /// Takes an item out of a creature's inventory and puts it on the named appendage, in the slot the item fits.
+    /// The appendage has to have that slot free and still work, and the creature needs something to grab with.
+    pub fn equip(&mut self, creature: Entity, item: Entity, appendage: &str) -> SimutronResult<()> {
+        self.check_can_handle_items(creature)?;
+        let equippable = match self.get_component::<Equippable>(item) {
+            Some(equippable) => *equippable,
+            None => return runtime_error!("Entity {:?} cannot be equipped.", item),
+        };
+        let body = match self.get_component::<Creature>(creature) {
+            Some(body) => body,
+            None => return runtime_error!("Creature not found in world."),
+        };
+        let part = match body.find(appendage) {
+            Some(part) => part,
+            None => return runtime_error!("{} has no {}.", body.name, appendage),
+        };
+        if !part.has_slot(equippable.slot) {
+            return runtime_error!(
+                "{}'s {} has no {:?} slot.",
+                body.name,
+                part.get_name(),
+                equippable.slot
+            );
+        }
+        if !part.is_working() {
+            return runtime_error!("{}'s {} cannot wear anything.", body.name, part.get_name());
+        }
+        let (name, id) = (body.name.clone(), part.get_id());
+        if self
+            .get_component::<Equipment>(creature)
+            .is_some_and(|equipment| equipment.get(id, equippable.slot).is_some())
+        {
+            return runtime_error!("{} already has something in that slot.", name);
+        }
+        let carried = self
+            .get_component::<Inventory>(creature)
+            .is_some_and(|inventory| inventory.items.contains(&item.0));
+        if !carried {
+            return runtime_error!("{} is not carrying {:?}.", name, item);
+        }
+        self.remove_from_inventory(creature, item.0)?;
+        match self.get_component_mut::<Equipment>(creature) {
+            Some(equipment) => equipment.insert(id, equippable.slot, item),
+            None => {
+                let mut equipment = Equipment::new();
+                equipment.insert(id, equippable.slot, item);
+                self.add_component(creature, equipment);
+            }
+        }
+        debug!("{} equipped {:?} as {:?}", name, item, equippable.slot);
+        Ok(())
+    }
+
+    /// Takes whatever is in a slot of the named appendage off and puts it back in the creature's inventory.
+    /// Returns the item.
+    pub fn unequip(
+        &mut self,
+        creature: Entity,
+        appendage: &str,
+        slot: EquipmentSlot,
+    ) -> SimutronResult<Entity> {
+        self.check_can_handle_items(creature)?;
+        let (name, id) = match self.get_component::<Creature>(creature) {
+            Some(body) => match body.find(appendage) {
+                Some(part) => (body.name.clone(), part.get_id()),
+                None => return runtime_error!("{} has no {}.", body.name, appendage),
+            },
+            None => return runtime_error!("Creature not found in world."),
+        };
+        if self.get_component::<Inventory>(creature).is_none() {
+            return runtime_error!("{} has no inventory to put things in.", name);
+        }
+        let item = match self
+            .get_component_mut::<Equipment>(creature)
+            .and_then(|equipment| equipment.remove(id, slot))
+        {
+            Some(item) => item,
+            None => return runtime_error!("{} has nothing in that slot.", name),
+        };
+        self.add_to_inventory(creature, item.0)?;
+        debug!("{} unequipped {:?}", name, item);
+        Ok(item)
+    }
+
+    /// Returns the armor worn on an appendage, from everything equipped there.
+    pub fn get_equipped_armor(&self, creature: Entity, appendage: AppendageId) -> Health {
+        let Some(equipment) = self.get_component::<Equipment>(creature) else {
+            return 0;
+        };
+        equipment
+            .get_items_on(appendage)
+            .filter_map(|item| self.get_component::<Equippable>(item))
+            .fold(0, |armor: Health, equippable| {
+                armor.saturating_add(equippable.armor.max(0))
+            })
+    }
+
+    /// Takes everything off an appendage that is no longer on the creature. Returns the items.
+    pub(crate) fn drop_equipment(
+        &mut self,
+        creature: Entity,
+        appendage: AppendageId,
+    ) -> Vec<Entity> {
+        match self.get_component_mut::<Equipment>(creature) {
+            Some(equipment) => equipment.remove_from(appendage),
+            None => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::creatures::creature_builder::{AppendageEffect, Health};
+use crate::ecs::component::Component;
+use crate::ecs::entity::Entity;
+
+/// The ways a wound can be mended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HealingMethod {
+    /// Bandages and salves for cuts and burns. Uses up a first aid supply.
+    FirstAid,
+    /// Sets crushed bones and cleans out infections. Uses up a surgery supply.
+    Surgery,
+    /// Mends any wound and purges poison, no supplies needed.
+    Magic,
+    /// Slow natural recovery while a creature rests.
+    Rest,
+}
+
+impl HealingMethod {
+    /// Returns the wounds the method can mend.
+    pub fn get_treats(&self) -> &'static [AppendageEffect] {
+        match self {
+            HealingMethod::FirstAid => &[AppendageEffect::Abrasion, AppendageEffect::Burn],
+            HealingMethod::Surgery => &[AppendageEffect::Crush, AppendageEffect::Infect],
+            HealingMethod::Magic => &[
+                AppendageEffect::Abrasion,
+                AppendageEffect::Crush,
+                AppendageEffect::Burn,
+                AppendageEffect::Poison,
+                AppendageEffect::Infect,
+            ],
+            HealingMethod::Rest => &[
+                AppendageEffect::Abrasion,
+                AppendageEffect::Crush,
+                AppendageEffect::Burn,
+            ],
+        }
+    }
+
+    /// Returns true if the healer needs a matching `MedicalSupply` in their inventory.
+    pub fn needs_supplies(&self) -> bool {
+        matches!(self, HealingMethod::FirstAid | HealingMethod::Surgery)
+    }
+
+    /// Share of the appendage's max health mended at once, unless the healing says otherwise.
+    /// For rest, the share mended every tick.
+    pub fn get_default_percent(&self) -> Health {
+        match self {
+            HealingMethod::FirstAid => 15,
+            HealingMethod::Surgery => 30,
+            HealingMethod::Magic => 50,
+            HealingMethod::Rest => 1,
+        }
+    }
+}
+
+/// A medkit, a surgeon's kit or the like. Lives on a prop and is used up after a number of uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MedicalSupply {
+    pub method: HealingMethod,
+    pub uses: u32,
+}
+impl Component for MedicalSupply {}
+
+/// One creature tending to another's appendage. Apply it with `World::heal`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Healing {
+    pub healer: Entity,
+    pub patient: Entity,
+    /// The appendage being tended to.
+    pub target: String,
+    pub method: HealingMethod,
+    /// Health to mend. None means the method's default share of the appendage's max health.
+    pub amount: Option<Health>,
+}
+
+impl Healing {
+    pub fn new(healer: Entity, patient: Entity, target: &str, method: HealingMethod) -> Self {
+        Self {
+            healer,
+            patient,
+            target: target.to_string(),
+            method,
+            amount: None,
+        }
+    }
+
+    pub fn with_amount(mut self, amount: Health) -> Self {
+        self.amount = Some(amount.max(0));
+        self
+    }
+}
+
+/// Marks a creature as resting. Every tick its working appendages recover a little.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Resting;
+impl Component for Resting {}
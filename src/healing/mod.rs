@@ -0,0 +1,4 @@
+/// Healing. First aid, surgery and magic mend wounds by type, and resting creatures recover on their own.
+/// Appendages that are disabled or lost are beyond any of it.
+pub mod components;
+pub(crate) mod systems;
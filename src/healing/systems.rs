@@ -0,0 +1,193 @@
+use crate::creatures::components::VitalState;
+use crate::creatures::creature_builder::{Appendage, AppendageEffect, EffectCause, Health};
+use crate::creatures::Creature;
+use crate::ecs::components::Inventory;
+use crate::ecs::entity::Entity;
+use crate::ecs::world::World;
+use crate::errors::{SimutronError, SimutronResult};
+use crate::healing::components::{Healing, HealingMethod, MedicalSupply, Resting};
+use crate::runtime_error;
+use log::debug;
+
+impl World {
+    /// Tends to a wounded appendage, mending the worst of the wounds the method treats first.
+    /// Disabled and lost appendages cannot be healed. First aid and surgery use up one use of
+    /// a matching supply from the healer's inventory, and only if there was something to mend.
+    /// The healer has to be a creature that can act.
+    /// Returns the health mended.
+    pub fn heal(&mut self, healing: &Healing) -> SimutronResult<Health> {
+        match self.get_component::<Creature>(healing.healer) {
+            Some(healer) if !healer.can_act() => {
+                return runtime_error!("{} is in no state to heal anyone.", healer.name);
+            }
+            Some(_) => {}
+            None => return runtime_error!("Healer {:?} not found in world.", healing.healer),
+        }
+        let body = match self.get_component::<Creature>(healing.patient) {
+            Some(body) => body,
+            None => return runtime_error!("Creature not found in world."),
+        };
+        let appendage = match body.corpus.find(&healing.target) {
+            Some(appendage) => appendage,
+            None => return runtime_error!("{} has no {}.", body.name, healing.target),
+        };
+        if !appendage.is_working() {
+            return runtime_error!("{}'s {} is beyond healing.", body.name, healing.target);
+        }
+        let amount = healing
+            .amount
+            .unwrap_or_else(|| appendage.get_share_of_max(healing.method.get_default_percent()));
+        let wounds = get_wounds(appendage, healing.method);
+        if wounds.is_empty() {
+            return Ok(0);
+        }
+
+        let supply = if healing.method.needs_supplies() {
+            self.check_can_handle_items(healing.healer)?;
+            match self.find_supply(healing.healer, healing.method) {
+                Some(supply) => Some(supply),
+                None => {
+                    return runtime_error!("No supplies for {:?} at hand.", healing.method);
+                }
+            }
+        } else {
+            None
+        };
+
+        let cause = EffectCause {
+            tick: self.get_clock().ticks,
+            source: Some(healing.healer),
+        };
+        let healed = self.mend(healing.patient, &healing.target, &wounds, amount, cause)?;
+        if let Some(supply) = supply {
+            self.use_supply(supply);
+        }
+        self.update_vital_state(healing.patient)?;
+        Ok(healed)
+    }
+
+    /// Lets a creature rest. It recovers a little every tick until it stops.
+    pub fn start_resting(&mut self, creature: Entity) -> SimutronResult<()> {
+        if self.get_component::<Creature>(creature).is_none() {
+            return runtime_error!("Creature not found in world.");
+        }
+        self.add_component(creature, Resting);
+        Ok(())
+    }
+
+    pub fn stop_resting(&mut self, creature: Entity) {
+        self.remove_component::<Resting>(creature);
+    }
+
+    /// Lets one tick of rest pass for a creature. The dying and the dead do not recover.
+    pub(crate) fn regenerate(&mut self, creature: Entity, tick: u64) -> SimutronResult<()> {
+        if self.get_component::<Resting>(creature).is_none() {
+            return Ok(());
+        }
+        let body = match self.get_component::<Creature>(creature) {
+            Some(body) => body,
+            None => return runtime_error!("Creature not found in world."),
+        };
+        if body.get_vital_state() > VitalState::Unconscious {
+            return Ok(());
+        }
+        let method = HealingMethod::Rest;
+        let mut plans = Vec::new();
+        for name in body.corpus.get_names() {
+            let Some(appendage) = body.corpus.find(&name) else {
+                continue;
+            };
+            if !appendage.is_working() {
+                continue;
+            }
+            let wounds = get_wounds(appendage, method);
+            if !wounds.is_empty() {
+                let amount = appendage.get_share_of_max(method.get_default_percent());
+                plans.push((name, wounds, amount));
+            }
+        }
+        let cause = EffectCause { tick, source: None };
+        for (name, wounds, amount) in plans {
+            self.mend(creature, &name, &wounds, amount, cause)?;
+        }
+        Ok(())
+    }
+
+    /// Spreads `amount` of healing over the wounds in order, and stops any status effect the healing treats.
+    /// Returns the health actually mended.
+    fn mend(
+        &mut self,
+        creature: Entity,
+        target: &str,
+        wounds: &[(AppendageEffect, Health)],
+        amount: Health,
+        cause: EffectCause,
+    ) -> SimutronResult<Health> {
+        let body = match self.get_component_mut::<Creature>(creature) {
+            Some(body) => body,
+            None => return runtime_error!("Creature not found in world."),
+        };
+        let before = body.corpus.find(target).map_or(0, |part| part.get_health());
+        let mut remaining = amount;
+        let mut treated = Vec::new();
+        for (effect, impact) in wounds {
+            if remaining <= 0 {
+                break;
+            }
+            let dose = remaining.min(impact.saturating_neg());
+            body.corpus.apply_effect_to(target, *effect, dose, cause);
+            remaining -= dose;
+            treated.push(*effect);
+        }
+        let after = body.corpus.find(target).map_or(0, |part| part.get_health());
+        debug!("{}'s {} mended by {}", body.name, target, after - before);
+        for effect in treated {
+            self.treat_statuses(creature, effect, target);
+        }
+        Ok(after.saturating_sub(before))
+    }
+
+    /// Looks through a creature's inventory for a supply usable with the method.
+    fn find_supply(&self, creature: Entity, method: HealingMethod) -> Option<Entity> {
+        let inventory = self.get_component::<Inventory>(creature)?;
+        inventory
+            .items
+            .iter()
+            .map(|item| Entity(*item))
+            .find(|item| {
+                self.get_component::<MedicalSupply>(*item)
+                    .is_some_and(|supply| supply.method == method && supply.uses > 0)
+            })
+    }
+
+    /// Uses up one use of a supply. An empty supply is thrown away.
+    fn use_supply(&mut self, supply: Entity) {
+        let empty = match self.get_component_mut::<MedicalSupply>(supply) {
+            Some(kit) => {
+                kit.uses = kit.uses.saturating_sub(1);
+                kit.uses == 0
+            }
+            None => false,
+        };
+        if empty {
+            for holder in self.get_entities_with::<Inventory>() {
+                if let Some(inventory) = self.get_component_mut::<Inventory>(holder) {
+                    inventory.items.retain(|item| *item != supply.0);
+                }
+            }
+            self.despawn_entity(supply);
+        }
+    }
+}
+
+/// Returns the wounds on an appendage a method can mend, worst first.
+fn get_wounds(appendage: &Appendage, method: HealingMethod) -> Vec<(AppendageEffect, Health)> {
+    let mut wounds: Vec<_> = method
+        .get_treats()
+        .iter()
+        .map(|effect| (*effect, appendage.get_effect_impact(*effect)))
+        .filter(|(_, impact)| *impact < 0)
+        .collect();
+    wounds.sort_by_key(|(_, impact)| *impact);
+    wounds
+}
@@ -7,48 +7,95 @@
 ///
 /// ```no_run
 /// use simutron::prelude::*;
-/// use simutron::creatures::humanoid::humanoid_corpus;
+/// use simutron::creatures::morphologies::humanoid::humanoid_corpus;
 ///
 /// let mut world = World::new();
 /// let creature = Creature {
 ///     name: "Hero".to_string(),
 ///     corpus: humanoid_corpus(),
-/// ;}
+/// };
 /// world.create_creature(creature);
 /// ```
 // Core modules
+pub mod combat;
 pub mod creatures;
+pub mod dice;
 pub mod ecs;
+pub mod encounters;
+pub mod equipment;
 pub mod errors;
+pub mod healing;
 pub mod map;
 pub mod props;
+pub mod status;
+pub mod time;
 
 // Prelude module for convenient imports
 pub mod prelude {
+    // Re-export combat types
+    pub use crate::combat::components::{
+        Attack, AttackAbility, AttackOutcome, CombatLog, CombatLogEntry, RollMode, Targeting,
+    };
+
     // Re-export commonly used types from creatures
     pub use crate::creatures::{
-        components::{CreatureActions, CreatureSheet},
-        creature_builder::{AppendageEffect, MorphologyBuilder},
+        components::{
+            Corpse, CreatureActions, CreatureSheet, DeathRules, MovementBudget, MovementModes,
+            SeveredAppendage, VitalEvents, VitalState, VitalStateChanged, Vitals,
+        },
+        creature_builder::{
+            Appendage, AppendageEffect, AppendageHealth, AppendageId, AppendageSpec, Capability,
+            CascadeRules, EffectRecord, Health, HealthThresholds, MorphologyBuilder,
+        },
         Creature,
     };
 
+    // Re-export dice types
+    pub use crate::dice::{
+        components::{Dice, DiceRoll, DieResult},
+        expression::{DiceExpression, DiceTerm, Keep},
+    };
+
     // Re-export ECS types
     pub use crate::ecs::{
         component::Component,
         components::{Inventory, Position, PropHealth},
         entity::Entity,
+        resource::Resource,
         world::World,
     };
 
+    // Re-export encounter types
+    pub use crate::encounters::components::{Combatant, Encounter};
+
+    // Re-export equipment types
+    pub use crate::equipment::components::{Equipment, EquipmentSlot, Equippable};
+
     // Re-export error types
     pub use crate::errors::{SimutronError, SimutronResult};
 
+    // Re-export healing types
+    pub use crate::healing::components::{Healing, HealingMethod, MedicalSupply, Resting};
+
     // Re-export map types
-    pub use crate::map::base_terrain::{BaseMap, Map, MapBuilder, Terrain, Tile};
+    pub use crate::map::base_terrain::{BaseMap, Map, MapBuilder, MovementMode, Terrain, Tile};
     // Re-export map types
     pub use crate::map::environments::forest::{Forest, ForestBuilder, ForestMaterial};
     // Re-export map types
     pub use crate::map::environments::Environments;
+    // Re-export map types
+    pub use crate::map::movement_report::{MovementReport, MovementStep, StopReason};
+    // Re-export map types
+    pub use crate::map::movement_rules::{DiagonalPolicy, MovementRules, TerrainCostPolicy};
+    // Re-export map types
+    pub use crate::map::topology::{GridTopology, HexCoordinates};
     // Re-export props types
     pub use crate::props::components::{Prop, PropAction, PropEffect};
+    // Re-export status effect types
+    pub use crate::status::components::{Stacking, StatusEffect, StatusEffects, StatusKind};
+
+    // Re-export time types
+    pub use crate::time::components::{
+        ActionTimers, GameClock, MovementOrder, TimeReport, TimedAction,
+    };
 }
@@ -1,5 +1,6 @@
 use crate::ecs::components::Position;
 use crate::ecs::entity::Entity;
+use crate::map::topology::GridTopology;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use uuid::Uuid;
@@ -11,7 +12,7 @@ use uuid::Uuid;
 /// Restricted: Minor penalty to movement.
 /// HighlyRestricted: Major penalty to movement.
 /// Blocking: Movement is not possible.
-pub(crate) enum Maneuverability {
+pub enum Maneuverability {
     Unrestricted = 1,
     Restricted = 2,
     HighlyRestricted = 3,
@@ -26,8 +27,47 @@ impl Maneuverability {
     }
 }
 
-pub(crate) trait MaterialManeuverability: 'static + Send + Sync {
+/// The ways a creature can get around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MovementMode {
+    Walk,
+    Swim,
+    Climb,
+    Fly,
+    Burrow,
+}
+
+impl MovementMode {
+    /// Returns the terrain modifier for crossing a tile in this mode, or None if this mode does not work there.
+    /// `native_mode` is the mode the tile's material asks for.
+    pub fn get_tile_modifier(
+        &self,
+        native_mode: MovementMode,
+        maneuverability: &Maneuverability,
+    ) -> Option<u32> {
+        match self {
+            // Walkers pay the full terrain cost and cannot get through blocking terrain.
+            MovementMode::Walk if *maneuverability == Maneuverability::Blocking => None,
+            MovementMode::Walk => Some(maneuverability.get_modifier() as u32),
+            // Fliers pass over everything.
+            MovementMode::Fly => Some(1),
+            // Burrowers dig under anything that is not water.
+            MovementMode::Burrow if native_mode == MovementMode::Swim => None,
+            MovementMode::Burrow => Some(1),
+            // Swimmers and climbers are only at home in their own terrain.
+            MovementMode::Swim | MovementMode::Climb if native_mode == *self => Some(1),
+            MovementMode::Swim | MovementMode::Climb => None,
+        }
+    }
+}
+
+pub trait MaterialManeuverability: 'static + Send + Sync {
     fn get_maneuverability(&self) -> Maneuverability;
+    /// Returns the movement mode needed to cross the material freely (e.g., swimming through a stream).
+    /// Walkers can still cross materials that ask for another mode, as long as they are not blocking.
+    fn get_movement_mode(&self) -> MovementMode {
+        MovementMode::Walk
+    }
 }
 
 pub trait Terrain: 'static + Debug + Clone + PartialEq {
@@ -40,7 +80,10 @@ pub trait Map {
     fn get_scale(&self) -> u32;
     fn get_width(&self) -> u32;
     fn get_height(&self) -> u32;
+    fn get_topology(&self) -> GridTopology;
     fn get_maneuverability(&self, position: Position) -> Option<Maneuverability>;
+    fn get_elevation(&self, position: Position) -> Option<u32>;
+    fn get_movement_mode(&self, position: Position) -> Option<MovementMode>;
 }
 #[derive(Debug, Clone, PartialEq)]
 pub enum Environments {
@@ -60,6 +103,9 @@ pub enum Environments {
 #[derive(Debug, Clone, PartialEq)]
 pub struct Tile<T: Terrain> {
     pub material: T::Material,
+    /// The height of the tile's surface, in the same unitless measure as the map scale.
+    /// Cliffs, balconies and pits are made by placing tiles of different elevations next to each other.
+    pub elevation: u32,
     // _terrain: PhantomData<T>, // We don't store T, but need to mark it as used.
 }
 
@@ -67,9 +113,17 @@ impl<T: Terrain> Tile<T> {
     pub fn new(default_material: T::Material) -> Self {
         Tile {
             material: default_material,
+            elevation: 0,
             // _terrain: Default::default(),
         }
     }
+
+    pub fn with_elevation(default_material: T::Material, elevation: u32) -> Self {
+        Tile {
+            material: default_material,
+            elevation,
+        }
+    }
 }
 /// The Map is made up of Tiles and Props
 /// Tiles represent the base terrain of the map
@@ -85,6 +139,8 @@ pub struct BaseMap<T: Terrain> {
     /// For example, if each tile represents a 5 m square, then tile_size would be 5.
     // A tile size is scoped to the map, so we only store the size once instead of in each tile which could become wasteful.
     pub scale: u32,
+    /// The shape of the grid. Decides adjacency, diagonals and distances on this map.
+    pub topology: GridTopology,
 
     // Manifolds
     pub tiles: Vec<Vec<Tile<T>>>,
@@ -106,6 +162,9 @@ impl<T: Terrain> Map for BaseMap<T> {
     fn get_height(&self) -> u32 {
         self.tiles.len() as u32
     }
+    fn get_topology(&self) -> GridTopology {
+        self.topology
+    }
     fn get_maneuverability(&self, position: Position) -> Option<Maneuverability> {
         let x = position.x;
         let y = position.y;
@@ -114,6 +173,17 @@ impl<T: Terrain> Map for BaseMap<T> {
                 .map(|tile| tile.material.get_maneuverability())
         })
     }
+    fn get_movement_mode(&self, position: Position) -> Option<MovementMode> {
+        self.tiles.get(position.y as usize).and_then(|row| {
+            row.get(position.x as usize)
+                .map(|tile| tile.material.get_movement_mode())
+        })
+    }
+    fn get_elevation(&self, position: Position) -> Option<u32> {
+        self.tiles
+            .get(position.y as usize)
+            .and_then(|row| row.get(position.x as usize).map(|tile| tile.elevation))
+    }
     // fn get_tile(&self, x: u32, y: u32) -> Option<&dyn MaterialManeuverability> {
     //     self.tiles.get(y as usize).and_then(|row| row.get(x as usize))
     // }
@@ -164,6 +234,7 @@ pub trait MapBuilder<T: Terrain> {
     fn new(width: u32, height: u32, tile_size: u32, default_tile: Tile<T>) -> Self;
     fn add_description(&mut self, description: &str) -> &mut Self;
     fn add_name(&mut self, map_name: &str) -> &mut Self;
+    fn set_topology(&mut self, topology: GridTopology) -> &mut Self;
     fn add_base_material(&mut self, x: u32, y: u32, tile: Tile<T>) -> &mut Self;
     fn build(&self) -> BaseMap<T>;
     fn get_tile_size(&self) -> u32;
@@ -0,0 +1,77 @@
+use crate::creatures::creature_builder::Health;
+use crate::map::base_terrain::MovementMode;
+
+/// Describes how a creature changes elevation when it steps from one tile to the next.
+/// All heights use the same unitless measure as the map scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerticalMovement {
+    /// Both tiles share the same elevation.
+    Level,
+    /// A short rise (no higher than one tile) that can be hopped up onto.
+    Jump { height: u32 },
+    /// A rise taller than one tile which must be scaled hand over hand.
+    Climb { height: u32 },
+    /// A short drop that can be stepped down safely.
+    Descend { height: u32 },
+    /// A drop taller than one tile. The creature lands hard and takes damage.
+    Fall { height: u32 },
+}
+
+/// Health lost for every tile fallen beyond the safe drop height.
+const FALL_DAMAGE_PER_TILE: i32 = 10;
+
+impl VerticalMovement {
+    /// Classifies the vertical part of a step between two elevations on a map of the given scale.
+    pub fn between(from_elevation: u32, to_elevation: u32, scale: u32) -> Self {
+        if to_elevation > from_elevation {
+            let height = to_elevation - from_elevation;
+            if height <= scale {
+                VerticalMovement::Jump { height }
+            } else {
+                VerticalMovement::Climb { height }
+            }
+        } else if to_elevation < from_elevation {
+            let height = from_elevation - to_elevation;
+            if height <= scale {
+                VerticalMovement::Descend { height }
+            } else {
+                VerticalMovement::Fall { height }
+            }
+        } else {
+            VerticalMovement::Level
+        }
+    }
+
+    /// Returns the extra movement cost of the vertical part of a step.
+    /// Jumping costs the height gained, climbing costs double the height gained, going down is free.
+    /// Climbers, fliers and burrowers never pay double.
+    pub fn get_cost(&self, mode: MovementMode) -> u32 {
+        match (self, mode) {
+            (VerticalMovement::Jump { height }, _) => *height,
+            (
+                VerticalMovement::Climb { height },
+                MovementMode::Climb | MovementMode::Fly | MovementMode::Burrow,
+            ) => *height,
+            (VerticalMovement::Climb { height }, _) => height.saturating_mul(2),
+            (VerticalMovement::Level, _)
+            | (VerticalMovement::Descend { .. }, _)
+            | (VerticalMovement::Fall { .. }, _) => 0,
+        }
+    }
+
+    /// Returns the (negative) health impact of landing after a fall, if any.
+    /// The first tile of height is a safe drop; every tile beyond that hurts.
+    /// Fliers glide down and burrowers tunnel down, so neither of them fall.
+    pub fn get_fall_damage(&self, mode: MovementMode, scale: u32) -> Option<Health> {
+        match (self, mode) {
+            (_, MovementMode::Fly | MovementMode::Burrow) => None,
+            (VerticalMovement::Fall { height }, _) => {
+                let scale = scale.max(1) as i32;
+                let excess = (*height as i32).saturating_sub(scale);
+                let damage = excess.saturating_mul(FALL_DAMAGE_PER_TILE) / scale;
+                Some(damage.saturating_neg())
+            }
+            _ => None,
+        }
+    }
+}
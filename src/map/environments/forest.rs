@@ -1,6 +1,8 @@
 use crate::map::base_terrain::{
-    BaseMap, Environments, Maneuverability, MapBuilder, MaterialManeuverability, Terrain, Tile,
+    BaseMap, Environments, Maneuverability, MapBuilder, MaterialManeuverability, MovementMode,
+    Terrain, Tile,
 };
+use crate::map::topology::GridTopology;
 use std::collections::HashMap;
 use uuid::Uuid;
 
@@ -31,6 +33,14 @@ impl MaterialManeuverability for ForestMaterial {
             ForestMaterial::Stream => Maneuverability::HighlyRestricted,
         }
     }
+
+    fn get_movement_mode(&self) -> MovementMode {
+        match self {
+            ForestMaterial::Stream => MovementMode::Swim,
+            ForestMaterial::FallenRocks => MovementMode::Climb,
+            _ => MovementMode::Walk,
+        }
+    }
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct Forest;
@@ -42,6 +52,7 @@ pub struct ForestBuilder {
     description: Option<String>,
     environment: Environments,
     tile_size: u32,
+    topology: GridTopology,
 }
 
 impl Terrain for Forest {
@@ -61,6 +72,7 @@ impl MapBuilder<Forest> for ForestBuilder {
             tile_size,
             tiles,
             environment,
+            topology: GridTopology::default(),
             map_name: None,
             description: None,
         }
@@ -78,6 +90,11 @@ impl MapBuilder<Forest> for ForestBuilder {
         self
     }
 
+    fn set_topology(&mut self, topology: GridTopology) -> &mut Self {
+        self.topology = topology;
+        self
+    }
+
     fn add_base_material(&mut self, x: u32, y: u32, tile: Tile<Forest>) -> &mut Self {
         self.tiles[y as usize][x as usize] = tile;
         self
@@ -87,6 +104,7 @@ impl MapBuilder<Forest> for ForestBuilder {
         BaseMap {
             id: self.id,
             scale: self.tile_size,
+            topology: self.topology,
             environment: self.environment.clone(),
             name: self.map_name.clone(),
             description: self.description.clone(),
@@ -1,3 +1,7 @@
 pub mod base_terrain;
+pub mod elevation;
 pub(crate) mod environments;
-mod maneuverability;
+pub mod movement_report;
+pub mod movement_rules;
+pub(crate) mod systems;
+pub mod topology;
@@ -0,0 +1,59 @@
+use crate::creatures::creature_builder::Health;
+use crate::ecs::components::Position;
+use crate::ecs::entity::Entity;
+use crate::map::base_terrain::MovementMode;
+
+/// Why a creature stopped moving along its requested path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// The whole path was walked.
+    Completed,
+    /// The creature could not afford the next step.
+    OutOfMovement,
+    /// None of the creature's movement modes can take the next step.
+    Blocked,
+    /// The next step leaves the map.
+    MapEdge,
+    /// Another creature is standing on the next tile.
+    Occupied,
+}
+
+/// A single step a creature actually took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovementStep {
+    /// Where the creature ended up after the step.
+    pub position: Position,
+    /// Movement spent on the step.
+    pub cost: u32,
+    /// The movement mode used for the step.
+    pub mode: MovementMode,
+    /// Whether the step cut across a corner of a square grid.
+    pub diagonal: bool,
+    /// The health impact of landing, if the step was a fall.
+    pub fall_damage: Option<Health>,
+}
+
+/// What happened when a creature was asked to move.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovementReport {
+    pub creature: Entity,
+    /// The tiles actually traversed, in order. Empty if the creature never left its tile.
+    pub steps: Vec<MovementStep>,
+    /// Total movement spent.
+    pub spent: u32,
+    /// Movement left over after the last step.
+    pub remaining: u32,
+    pub stop_reason: StopReason,
+}
+
+impl MovementReport {
+    /// Returns true if the creature walked the whole requested path.
+    pub fn is_complete(&self) -> bool {
+        self.stop_reason == StopReason::Completed
+    }
+
+    /// Returns where the creature stopped, or None if it never moved.
+    pub fn get_final_position(&self) -> Option<Position> {
+        self.steps.last().map(|step| step.position)
+    }
+}
@@ -0,0 +1,100 @@
+use crate::ecs::resource::Resource;
+
+/// How much a diagonal step costs on a square grid with eight neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiagonalPolicy {
+    /// The first diagonal costs one tile, the second costs two, then it repeats.
+    /// Only diagonal steps count towards the alternation, so orthogonal steps in between do not
+    /// change which diagonal is the expensive one.
+    #[default]
+    Alternating,
+    /// Every diagonal costs one tile times the square root of two, rounded.
+    Euclidean,
+    /// Every diagonal costs one tile, the same as an orthogonal step.
+    Chebyshev,
+    /// Every diagonal costs two tiles, as if the creature walked around the corner.
+    Manhattan,
+}
+
+/// Which tile's maneuverability multiplies the cost of a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TerrainCostPolicy {
+    /// The tile being left. Getting out of a stream is the hard part.
+    #[default]
+    LeavingTile,
+    /// The tile being entered. Getting into a stream is the hard part.
+    EnteringTile,
+    /// Whichever of the two tiles is harder to cross.
+    Harshest,
+    /// Terrain does not affect the cost of movement.
+    Ignored,
+}
+
+/// The rule set `move_creature` follows. Insert it as a resource on the World to change how movement works.
+/// Without one, the default rules are used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MovementRules {
+    pub diagonals: DiagonalPolicy,
+    pub terrain: TerrainCostPolicy,
+    /// When true, a creature that cannot afford its whole path moves as far as it can.
+    /// When false, the move is rejected and the creature stays put.
+    pub allow_partial_moves: bool,
+}
+
+impl Resource for MovementRules {}
+
+impl Default for MovementRules {
+    fn default() -> Self {
+        MovementRules {
+            diagonals: DiagonalPolicy::Alternating,
+            terrain: TerrainCostPolicy::LeavingTile,
+            allow_partial_moves: true,
+        }
+    }
+}
+
+impl DiagonalPolicy {
+    /// Returns the cost of a diagonal step given how many diagonals were already taken along the path.
+    pub fn get_diagonal_cost(&self, scale: u32, diagonals_taken: u32) -> u32 {
+        match self {
+            DiagonalPolicy::Alternating => {
+                // Therefore: if even 1, if odd cost * 2
+                if (diagonals_taken & 1) == 1 {
+                    scale << 1
+                } else {
+                    scale
+                }
+            }
+            DiagonalPolicy::Euclidean => (scale as f64 * std::f64::consts::SQRT_2).round() as u32,
+            DiagonalPolicy::Chebyshev => scale,
+            DiagonalPolicy::Manhattan => scale << 1,
+        }
+    }
+
+    /// Returns the cheapest cost of crossing `dx` by `dy` tiles on open ground.
+    pub fn get_distance(&self, dx: u32, dy: u32, scale: u32) -> u32 {
+        let diagonals = dx.min(dy);
+        let straights = dx.max(dy) - diagonals;
+        let diagonal_cost = match self {
+            DiagonalPolicy::Alternating => (diagonals + diagonals / 2) * scale,
+            DiagonalPolicy::Euclidean => {
+                (diagonals as f64 * scale as f64 * std::f64::consts::SQRT_2).round() as u32
+            }
+            DiagonalPolicy::Chebyshev => diagonals * scale,
+            DiagonalPolicy::Manhattan => diagonals * scale * 2,
+        };
+        straights * scale + diagonal_cost
+    }
+}
+
+impl TerrainCostPolicy {
+    /// Picks the multiplier for a step between two tiles, given each tile's own modifier.
+    pub fn get_modifier(&self, leaving: u32, entering: u32) -> u32 {
+        match self {
+            TerrainCostPolicy::LeavingTile => leaving,
+            TerrainCostPolicy::EnteringTile => entering,
+            TerrainCostPolicy::Harshest => leaving.max(entering),
+            TerrainCostPolicy::Ignored => 1,
+        }
+    }
+}
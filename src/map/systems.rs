@@ -0,0 +1,132 @@
+use crate::ecs::components::Position;
+use crate::ecs::world::World;
+use crate::errors::{SimutronError, SimutronResult};
+use crate::map::base_terrain::{Map, MovementMode};
+use crate::map::topology::GridTopology;
+use crate::runtime_error;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+impl World {
+    fn get_shared_map(&self, from: &Position, to: &Position) -> SimutronResult<&dyn Map> {
+        let map_id = match (&from.map, &to.map) {
+            (Some(from_map), Some(to_map)) if from_map == to_map => from_map,
+            (Some(_), Some(_)) => return runtime_error!("Positions are on different maps."),
+            _ => return runtime_error!("Position has no map assigned."),
+        };
+        match self.get_map(map_id) {
+            Some(m) => Ok(m),
+            None => runtime_error!("Map {} not found.", map_id),
+        }
+    }
+
+    /// Returns how far apart two positions are in map units, ignoring terrain.
+    /// Distance follows the map's topology and, on square grids with diagonals, the diagonal policy of the movement rules.
+    pub fn get_distance(&self, from: Position, to: Position) -> SimutronResult<u32> {
+        let map = self.get_shared_map(&from, &to)?;
+        let scale = map.get_scale();
+        let distance = match map.get_topology() {
+            GridTopology::Square8 => self.get_movement_rules().diagonals.get_distance(
+                from.x.abs_diff(to.x),
+                from.y.abs_diff(to.y),
+                scale,
+            ),
+            topology => topology.get_distance((from.x, from.y), (to.x, to.y)) * scale,
+        };
+        Ok(distance)
+    }
+
+    /// Finds the cheapest walking path between two positions using the same costs as `move_creature`.
+    /// The returned path excludes the starting tile and can be handed straight to `move_creature`.
+    /// Blocking tiles are never entered.
+    pub fn find_path(&self, from: Position, to: Position) -> SimutronResult<Vec<Position>> {
+        let map = self.get_shared_map(&from, &to)?;
+        let (width, height) = (map.get_width(), map.get_height());
+        if from.x >= width || from.y >= height || to.x >= width || to.y >= height {
+            return runtime_error!("Position is out of map bounds.");
+        }
+        let topology = map.get_topology();
+
+        // Every search node is a tile plus whether the next diagonal step is the expensive one.
+        type Node = (u32, u32, u32);
+        let start: Node = (from.x, from.y, 0);
+        let mut costs: HashMap<Node, u32> = HashMap::from([(start, 0)]);
+        let mut came_from: HashMap<Node, (Node, Position)> = HashMap::new();
+        let mut standing_at: HashMap<Node, Position> = HashMap::from([(start, from)]);
+        let mut frontier = BinaryHeap::from([Reverse((0u32, start))]);
+
+        while let Some(Reverse((cost, node))) = frontier.pop() {
+            if node.0 == to.x && node.1 == to.y {
+                let mut path = Vec::new();
+                let mut current = node;
+                while let Some((previous, position)) = came_from.get(&current) {
+                    path.push(*position);
+                    current = *previous;
+                }
+                path.reverse();
+                return Ok(path);
+            }
+            if cost > costs.get(&node).copied().unwrap_or(u32::MAX) {
+                continue;
+            }
+            let here = standing_at[&node];
+            for (x, y) in topology.get_neighbours(node.0, node.1) {
+                if x < 0 || y < 0 || x >= width as i64 || y >= height as i64 {
+                    continue;
+                }
+                let next = Position {
+                    map: from.map,
+                    x: x as u32,
+                    y: y as u32,
+                    z: 0,
+                };
+                let step = match self.get_step_cost(here, next, node.2, MovementMode::Walk)? {
+                    Some(step) => step,
+                    None => continue,
+                };
+                let next_node = (next.x, next.y, (node.2 + step.diagonal as u32) & 1);
+                let next_cost = cost.saturating_add(step.cost);
+                if next_cost < costs.get(&next_node).copied().unwrap_or(u32::MAX) {
+                    costs.insert(next_node, next_cost);
+                    came_from.insert(next_node, (node, step.destination));
+                    standing_at.insert(next_node, step.destination);
+                    frontier.push(Reverse((next_cost, next_node)));
+                }
+            }
+        }
+        runtime_error!("No path found between the two positions.")
+    }
+
+    /// Checks whether anything standing at `from` can see `to`.
+    /// The sight line runs between the elevations of the two positions and is blocked by any tile in between
+    /// whose surface rises above the line. Both positions must be on the same map.
+    pub fn has_line_of_sight(&self, from: Position, to: Position) -> SimutronResult<bool> {
+        let map = self.get_shared_map(&from, &to)?;
+        if map.get_elevation(from).is_none() || map.get_elevation(to).is_none() {
+            return runtime_error!("Position is out of map bounds.");
+        }
+
+        let dx = to.x as i64 - from.x as i64;
+        let dy = to.y as i64 - from.y as i64;
+        let dz = to.z as i64 - from.z as i64;
+        let steps = dx.abs().max(dy.abs());
+        // Sample every tile the line crosses, skipping the two end points.
+        for step in 1..steps {
+            let x = from.x as i64 + (dx * step) / steps;
+            let y = from.y as i64 + (dy * step) / steps;
+            let sight_height = from.z as i64 + (dz * step) / steps;
+            let sample = Position {
+                map: from.map,
+                x: x as u32,
+                y: y as u32,
+                z: 0,
+            };
+            if let Some(elevation) = map.get_elevation(sample)
+                && elevation as i64 > sight_height
+            {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,121 @@
+/// The shape of the grid a map is laid out on.
+/// The topology decides which tiles are neighbours, which steps are diagonal and how far apart two tiles are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GridTopology {
+    /// Square tiles. A creature may step onto any of the eight surrounding tiles.
+    #[default]
+    Square8,
+    /// Square tiles. A creature may only step across the four shared edges.
+    Square4,
+    /// Hexagonal tiles. Every tile has six neighbours and no step is diagonal.
+    Hex(HexCoordinates),
+}
+
+/// How the `x` and `y` of a Position are laid out on a hex grid.
+/// See <https://www.redblobgames.com/grids/hexagons/> for pictures of each layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexCoordinates {
+    /// `x` is the q axis and `y` is the r axis. Rectangular maps come out as rhombuses.
+    Axial,
+    /// Pointy topped rows, odd rows are shoved half a tile right.
+    OddRows,
+    /// Pointy topped rows, even rows are shoved half a tile right.
+    EvenRows,
+    /// Flat topped columns, odd columns are shoved half a tile down.
+    OddColumns,
+    /// Flat topped columns, even columns are shoved half a tile down.
+    EvenColumns,
+}
+
+const SQUARE_4_DIRECTIONS: [(i64, i64); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+const SQUARE_8_DIRECTIONS: [(i64, i64); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+const AXIAL_DIRECTIONS: [(i64, i64); 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+impl HexCoordinates {
+    /// Converts grid coordinates into axial (q, r) coordinates.
+    fn grid_to_axial(self, x: i64, y: i64) -> (i64, i64) {
+        match self {
+            HexCoordinates::Axial => (x, y),
+            HexCoordinates::OddRows => (x - (y - (y & 1)) / 2, y),
+            HexCoordinates::EvenRows => (x - (y + (y & 1)) / 2, y),
+            HexCoordinates::OddColumns => (x, y - (x - (x & 1)) / 2),
+            HexCoordinates::EvenColumns => (x, y - (x + (x & 1)) / 2),
+        }
+    }
+
+    /// Converts axial (q, r) coordinates back into grid coordinates.
+    fn axial_to_grid(self, q: i64, r: i64) -> (i64, i64) {
+        match self {
+            HexCoordinates::Axial => (q, r),
+            HexCoordinates::OddRows => (q + (r - (r & 1)) / 2, r),
+            HexCoordinates::EvenRows => (q + (r + (r & 1)) / 2, r),
+            HexCoordinates::OddColumns => (q, r + (q - (q & 1)) / 2),
+            HexCoordinates::EvenColumns => (q, r + (q + (q & 1)) / 2),
+        }
+    }
+}
+
+impl GridTopology {
+    /// Returns the coordinates of every tile next to (x, y).
+    /// Coordinates may fall outside of the map (or below zero), so callers must bounds check them.
+    pub fn get_neighbours(&self, x: u32, y: u32) -> Vec<(i64, i64)> {
+        let (x, y) = (x as i64, y as i64);
+        match self {
+            GridTopology::Square8 => SQUARE_8_DIRECTIONS
+                .iter()
+                .map(|(dx, dy)| (x + dx, y + dy))
+                .collect(),
+            GridTopology::Square4 => SQUARE_4_DIRECTIONS
+                .iter()
+                .map(|(dx, dy)| (x + dx, y + dy))
+                .collect(),
+            GridTopology::Hex(layout) => {
+                let (q, r) = layout.grid_to_axial(x, y);
+                AXIAL_DIRECTIONS
+                    .iter()
+                    .map(|(dq, dr)| layout.axial_to_grid(q + dq, r + dr))
+                    .collect()
+            }
+        }
+    }
+
+    /// Returns true if a creature can step directly from one tile to the other.
+    pub fn is_adjacent(&self, from: (u32, u32), to: (u32, u32)) -> bool {
+        self.get_distance(from, to) == 1
+    }
+
+    /// Returns true if the step between two adjacent tiles crosses a corner instead of an edge.
+    /// Only square grids with eight neighbours have diagonals.
+    pub fn is_diagonal(&self, from: (u32, u32), to: (u32, u32)) -> bool {
+        match self {
+            GridTopology::Square8 => from.0.abs_diff(to.0) == 1 && from.1.abs_diff(to.1) == 1,
+            GridTopology::Square4 | GridTopology::Hex(_) => false,
+        }
+    }
+
+    /// Returns the fewest number of steps it takes to walk between two tiles, ignoring terrain.
+    pub fn get_distance(&self, from: (u32, u32), to: (u32, u32)) -> u32 {
+        let dx = from.0.abs_diff(to.0);
+        let dy = from.1.abs_diff(to.1);
+        match self {
+            GridTopology::Square8 => dx.max(dy),
+            GridTopology::Square4 => dx + dy,
+            GridTopology::Hex(layout) => {
+                let (from_q, from_r) = layout.grid_to_axial(from.0 as i64, from.1 as i64);
+                let (to_q, to_r) = layout.grid_to_axial(to.0 as i64, to.1 as i64);
+                let dq = from_q - to_q;
+                let dr = from_r - to_r;
+                ((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as u32
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::creatures::creature_builder::Capability;
+use crate::creatures::Creature;
 use crate::ecs::components::{Inventory, Position, PropHealth};
 use crate::ecs::world::World;
 use crate::errors::{SimutronError, SimutronResult};
@@ -13,6 +15,10 @@ impl World {
     /// Returns a copy of the Prop after the action is applied as a curtesy.
     /// You'll need to get a reference if you want to do additional changes.
     pub fn apply_prop_action(&mut self, action: &PropAction) -> SimutronResult<Prop> {
+        // Looking at a prop does not take hands.
+        if !matches!(action.effect, PropEffect::Inspect) {
+            self.check_can_handle_items(action.from)?;
+        }
         match action.effect {
             PropEffect::Fix => self.prop_fix(action)?,
             PropEffect::Damage => self.prop_damage(action)?,
@@ -75,6 +81,33 @@ impl World {
         }
     }
 
+    /// `actor` puts an item into an inventory, its own or someone else's. Creatures need something to grab with.
+    pub fn give_item(&mut self, actor: Entity, add_to: Entity, item: Uuid) -> SimutronResult<Prop> {
+        self.check_can_handle_items(actor)?;
+        self.add_to_inventory(add_to, item)
+    }
+
+    /// `actor` takes an item out of an inventory, its own or someone else's. Creatures need something to grab with.
+    pub fn take_item(
+        &mut self,
+        actor: Entity,
+        take_from: Entity,
+        item: Uuid,
+    ) -> SimutronResult<Prop> {
+        self.check_can_handle_items(actor)?;
+        self.remove_from_inventory(take_from, item)
+    }
+
+    /// Creatures need something to grab items with. Anything that is not a creature is not checked.
+    pub(crate) fn check_can_handle_items(&self, entity: Entity) -> SimutronResult<()> {
+        match self.get_component::<Creature>(entity) {
+            Some(creature) if !creature.is_capable(Capability::Grasp) => {
+                runtime_error!("{} has nothing left to handle items with.", creature.name)
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Adds any attached components to the Prop component for inspection.
     /// Allows/expects a function to call the prop once it has mutated.
     fn prop_inspect(&mut self, action: &PropAction) -> SimutronResult<()> {
@@ -0,0 +1,175 @@
+use crate::creatures::creature_builder::{AppendageEffect, Health};
+use crate::ecs::component::Component;
+use crate::ecs::entity::Entity;
+
+/// The kinds of lasting conditions a creature can suffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// Cuts an appendage a little more every tick. Stopped by healing cuts on that appendage.
+    Bleeding,
+    /// Burns an appendage every tick. Stopped by healing burns on that appendage.
+    Burning,
+    /// Poisons an appendage every tick. Stopped by an antidote on that appendage.
+    Poisoned,
+    /// The creature loses its turns in encounters.
+    Stunned,
+}
+
+impl StatusKind {
+    /// Returns the effect dealt to the appendage every tick, if any.
+    pub fn get_tick_effect(&self) -> Option<AppendageEffect> {
+        match self {
+            StatusKind::Bleeding => Some(AppendageEffect::Abrasion),
+            StatusKind::Burning => Some(AppendageEffect::Burn),
+            StatusKind::Poisoned => Some(AppendageEffect::Poison),
+            StatusKind::Stunned => None,
+        }
+    }
+
+    /// How a fresh dose of this kind combines with one already there, unless the effect says otherwise.
+    pub fn get_default_stacking(&self) -> Stacking {
+        match self {
+            StatusKind::Bleeding => Stacking::Intensify,
+            StatusKind::Burning => Stacking::Refresh,
+            StatusKind::Poisoned | StatusKind::Stunned => Stacking::Extend,
+        }
+    }
+
+    /// Damage dealt every tick, unless the effect says otherwise.
+    pub fn get_default_potency(&self) -> Health {
+        match self {
+            StatusKind::Bleeding => 2,
+            StatusKind::Burning => 3,
+            StatusKind::Poisoned => 1,
+            StatusKind::Stunned => 0,
+        }
+    }
+}
+
+/// How a status effect combines with one of the same kind already on the same appendage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stacking {
+    /// Keeps a single effect with the longer duration and the higher potency.
+    Refresh,
+    /// Adds the durations together.
+    Extend,
+    /// Adds the potencies together and keeps the longer duration.
+    Intensify,
+    /// Runs alongside the existing effect as a separate one.
+    Independent,
+}
+
+/// A lasting condition on a creature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    /// The appendage it hurts. None means the root of the body.
+    pub appendage: Option<String>,
+    /// Damage dealt every tick.
+    pub potency: Health,
+    /// Ticks left before it wears off.
+    pub remaining: u32,
+    /// Who caused it.
+    pub source: Option<Entity>,
+    pub stacking: Stacking,
+}
+
+impl StatusEffect {
+    pub fn new(kind: StatusKind, duration: u32) -> Self {
+        Self {
+            kind,
+            appendage: None,
+            potency: kind.get_default_potency(),
+            remaining: duration,
+            source: None,
+            stacking: kind.get_default_stacking(),
+        }
+    }
+
+    pub fn with_appendage(mut self, appendage: &str) -> Self {
+        self.appendage = Some(appendage.to_string());
+        self
+    }
+
+    pub fn with_potency(mut self, potency: Health) -> Self {
+        self.potency = potency.max(0);
+        self
+    }
+
+    pub fn with_source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn with_stacking(mut self, stacking: Stacking) -> Self {
+        self.stacking = stacking;
+        self
+    }
+}
+
+/// Every status effect a creature is suffering from. `World::tick` runs them down.
+#[derive(Debug, Clone, Default)]
+pub struct StatusEffects {
+    pub(crate) effects: Vec<StatusEffect>,
+}
+impl Component for StatusEffects {}
+
+impl StatusEffects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_effects(&self) -> &[StatusEffect] {
+        &self.effects
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.effects.iter().any(|effect| effect.kind == kind)
+    }
+
+    /// Returns the first effect of a kind, if the creature suffers from it.
+    pub fn get(&self, kind: StatusKind) -> Option<&StatusEffect> {
+        self.effects.iter().find(|effect| effect.kind == kind)
+    }
+
+    /// Adds an effect, stacking it onto a matching one according to the new effect's stacking rule.
+    pub(crate) fn add(&mut self, effect: StatusEffect) {
+        let existing = self.effects.iter_mut().find(|existing| {
+            existing.kind == effect.kind && existing.appendage == effect.appendage
+        });
+        let existing = match existing {
+            Some(existing) if effect.stacking != Stacking::Independent => existing,
+            _ => {
+                self.effects.push(effect);
+                return;
+            }
+        };
+        match effect.stacking {
+            Stacking::Refresh => {
+                existing.remaining = existing.remaining.max(effect.remaining);
+                existing.potency = existing.potency.max(effect.potency);
+            }
+            Stacking::Extend => {
+                existing.remaining = existing.remaining.saturating_add(effect.remaining);
+            }
+            Stacking::Intensify => {
+                existing.remaining = existing.remaining.max(effect.remaining);
+                existing.potency = existing.potency.saturating_add(effect.potency);
+            }
+            Stacking::Independent => {}
+        }
+        if effect.source.is_some() {
+            existing.source = effect.source;
+        }
+    }
+
+    /// Takes out every effect matching the filter and hands them back.
+    pub(crate) fn remove_where(
+        &mut self,
+        filter: impl Fn(&StatusEffect) -> bool,
+    ) -> Vec<StatusEffect> {
+        let (removed, kept) = self.effects.drain(..).partition(|effect| filter(effect));
+        self.effects = kept;
+        removed
+    }
+}
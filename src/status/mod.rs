@@ -0,0 +1,4 @@
+/// Status effects. Bleeding, burning, poison and stuns that last a number of ticks,
+/// hurt their creature every tick and stack when applied again.
+pub mod components;
+pub(crate) mod systems;
@@ -0,0 +1,128 @@
+use crate::creatures::creature_builder::{AppendageEffect, EffectCause};
+use crate::creatures::Creature;
+use crate::ecs::entity::Entity;
+use crate::ecs::world::World;
+use crate::errors::{SimutronError, SimutronResult};
+use crate::runtime_error;
+use crate::status::components::{StatusEffect, StatusEffects, StatusKind};
+use log::debug;
+
+impl World {
+    /// Puts a status effect on a creature, stacking it with any matching effect already there.
+    /// The appendage may be given by name or by path, and is stored by name.
+    pub fn apply_status(
+        &mut self,
+        creature: Entity,
+        mut effect: StatusEffect,
+    ) -> SimutronResult<()> {
+        let body = match self.get_component::<Creature>(creature) {
+            Some(body) => body,
+            None => return runtime_error!("Creature not found in world."),
+        };
+        if let Some(target) = &effect.appendage {
+            match body.find(target) {
+                Some(appendage) => effect.appendage = Some(appendage.get_name().to_string()),
+                None => return runtime_error!("{} has no {}.", body.name, target),
+            }
+        }
+        debug!("{} is now {:?}", body.name, effect.kind);
+        match self.get_component_mut::<StatusEffects>(creature) {
+            Some(statuses) => statuses.add(effect),
+            None => {
+                let mut statuses = StatusEffects::new();
+                statuses.add(effect);
+                self.add_component(creature, statuses);
+            }
+        }
+        Ok(())
+    }
+
+    /// Cures a creature of every status effect of a kind and returns what was removed.
+    pub fn remove_status(
+        &mut self,
+        creature: Entity,
+        kind: StatusKind,
+    ) -> SimutronResult<Vec<StatusEffect>> {
+        if self.get_component::<Creature>(creature).is_none() {
+            return runtime_error!("Creature not found in world.");
+        }
+        Ok(match self.get_component_mut::<StatusEffects>(creature) {
+            Some(statuses) => statuses.remove_where(|effect| effect.kind == kind),
+            None => Vec::new(),
+        })
+    }
+
+    pub fn is_stunned(&self, creature: Entity) -> bool {
+        self.get_component::<StatusEffects>(creature)
+            .is_some_and(|statuses| statuses.has(StatusKind::Stunned))
+    }
+
+    /// Healing an appendage stops the status effects that hurt it the same way, e.g. healing cuts stops bleeding.
+    pub(crate) fn treat_statuses(
+        &mut self,
+        creature: Entity,
+        effect: AppendageEffect,
+        target: &str,
+    ) {
+        let (root, target) = match self.get_component::<Creature>(creature) {
+            Some(body) => match body.find(target) {
+                Some(appendage) => (
+                    body.corpus.get_name().to_string(),
+                    appendage.get_name().to_string(),
+                ),
+                None => return,
+            },
+            None => return,
+        };
+        if let Some(statuses) = self.get_component_mut::<StatusEffects>(creature) {
+            statuses.remove_where(|status| {
+                status.kind.get_tick_effect() == Some(effect)
+                    && status.appendage.as_deref().unwrap_or(&root) == target.as_str()
+            });
+        }
+    }
+
+    /// Lets one tick pass for a creature's status effects. Each one hurts its appendage and counts down,
+    /// and wears off once it runs out or its appendage is gone.
+    pub(crate) fn tick_status_effects(
+        &mut self,
+        creature: Entity,
+        tick: u64,
+    ) -> SimutronResult<()> {
+        let mut effects = match self.get_component_mut::<StatusEffects>(creature) {
+            Some(statuses) => std::mem::take(&mut statuses.effects),
+            None => return Ok(()),
+        };
+        let body = match self.get_component_mut::<Creature>(creature) {
+            Some(body) => body,
+            None => return runtime_error!("Creature not found in world."),
+        };
+        effects.retain_mut(|status| {
+            let target = status
+                .appendage
+                .clone()
+                .unwrap_or_else(|| body.corpus.get_name().to_string());
+            if body.corpus.find(&target).is_none() {
+                return false;
+            }
+            if let Some(effect) = status.kind.get_tick_effect()
+                && status.potency > 0
+            {
+                let cause = EffectCause {
+                    tick,
+                    source: status.source,
+                };
+                body.corpus
+                    .apply_internal_effect_to(&target, effect, -status.potency, cause);
+            }
+            status.remaining = status.remaining.saturating_sub(1);
+            status.remaining > 0
+        });
+        if let Some(statuses) = self.get_component_mut::<StatusEffects>(creature) {
+            // Keep anything added while the effects were out.
+            effects.append(&mut statuses.effects);
+            statuses.effects = effects;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use crate::ecs::component::Component;
+use crate::ecs::components::Position;
+use crate::ecs::entity::Entity;
+use crate::ecs::resource::Resource;
+use crate::map::movement_report::MovementReport;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+/// Keeps the time of the world. Real-time play drives it with `World::advance`, and encounters move it one turn every round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameClock {
+    /// Time passed since the clock started.
+    pub elapsed: Duration,
+    /// How long a turn lasts. A creature covers its full speed in one turn,
+    /// so this converts speeds and movement costs into time. Wounds progress once per turn.
+    pub turn_length: Duration,
+    /// Time passed since the last tick.
+    pub tick_progress: Duration,
+    /// How many ticks have run. Effects on appendages are stamped with it.
+    pub ticks: u64,
+}
+
+impl Resource for GameClock {}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(6))
+    }
+}
+
+impl GameClock {
+    pub fn new(turn_length: Duration) -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            turn_length,
+            tick_progress: Duration::ZERO,
+            ticks: 0,
+        }
+    }
+
+    /// Returns how long it takes to spend `movement` at `speed`. None if the creature cannot move at all.
+    pub fn get_travel_time(&self, movement: u32, speed: u32) -> Option<Duration> {
+        if speed == 0 {
+            return None;
+        }
+        Some(self.turn_length.mul_f64(movement as f64 / speed as f64))
+    }
+
+    /// Returns how much movement a creature at `speed` can afford in the given time.
+    pub fn get_movement_for(&self, time: Duration, speed: u32) -> u32 {
+        if self.turn_length.is_zero() {
+            return u32::MAX;
+        }
+        (time.as_secs_f64() / self.turn_length.as_secs_f64() * speed as f64) as u32
+    }
+}
+
+/// A path a creature walks over time in real-time play.
+/// `World::advance` moves it along as time passes and removes the order once it is done.
+#[derive(Debug, Clone, Default)]
+pub struct MovementOrder {
+    /// Tiles still to be walked, in order.
+    pub path: VecDeque<Position>,
+    /// Time banked towards the next step.
+    pub progress: Duration,
+    /// Diagonals already taken along the path, so alternating diagonals keep alternating.
+    pub diagonals_taken: u32,
+}
+impl Component for MovementOrder {}
+
+/// An action that takes time to finish.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedAction {
+    pub name: String,
+    /// Time left until the action completes.
+    pub remaining: Duration,
+    /// How long the action is unavailable after it completes.
+    pub cooldown: Duration,
+}
+
+/// The action a creature is busy with and the actions it has to wait on before using again.
+#[derive(Debug, Clone, Default)]
+pub struct ActionTimers {
+    pub current: Option<TimedAction>,
+    pub cooldowns: HashMap<String, Duration>,
+}
+impl Component for ActionTimers {}
+
+impl ActionTimers {
+    pub fn is_busy(&self) -> bool {
+        self.current.is_some()
+    }
+
+    pub fn is_ready(&self, action: &str) -> bool {
+        !self.cooldowns.contains_key(action)
+    }
+
+    /// Lets time pass. Returns the name of the current action if it completed.
+    pub(crate) fn advance(&mut self, dt: Duration) -> Option<String> {
+        self.cooldowns.retain(|_, remaining| {
+            *remaining = remaining.saturating_sub(dt);
+            !remaining.is_zero()
+        });
+        let action = self.current.as_mut()?;
+        if action.remaining > dt {
+            action.remaining -= dt;
+            return None;
+        }
+        let overflow = dt - action.remaining;
+        let action = self.current.take()?;
+        let cooldown = action.cooldown.saturating_sub(overflow);
+        if !cooldown.is_zero() {
+            self.cooldowns.insert(action.name.clone(), cooldown);
+        }
+        Some(action.name)
+    }
+}
+
+/// What happened during a call to `World::advance`.
+#[derive(Debug, Clone, Default)]
+pub struct TimeReport {
+    /// How much time passed.
+    pub elapsed: Duration,
+    /// How many ticks of periodic effects ran.
+    pub ticks: u32,
+    /// Movement made by every creature following a movement order.
+    pub movements: Vec<MovementReport>,
+    /// Actions that completed, along with who performed them.
+    pub completed_actions: Vec<(Entity, String)>,
+}
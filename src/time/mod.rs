@@ -0,0 +1,4 @@
+/// Real-time play. A game clock moves the world forward by elapsed time instead of by turns.
+/// Creatures walk their paths, actions finish and cooldowns run out as time passes.
+pub mod components;
+pub(crate) mod systems;
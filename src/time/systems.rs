@@ -0,0 +1,238 @@
+use crate::creatures::components::VitalState;
+use crate::creatures::creature_builder::{AppendageEffect, EffectCause, Health};
+use crate::creatures::Creature;
+use crate::ecs::components::Position;
+use crate::ecs::entity::Entity;
+use crate::ecs::world::World;
+use crate::errors::{SimutronError, SimutronResult};
+use crate::map::movement_report::{MovementReport, StopReason};
+use crate::runtime_error;
+use crate::time::components::{ActionTimers, GameClock, MovementOrder, TimeReport, TimedAction};
+use log::debug;
+use std::time::Duration;
+
+/// Share of its max health a dying creature's weakest vital appendage loses every tick.
+const DYING_BLEED_PERCENT: Health = 5;
+
+impl World {
+    /// Returns the world's clock, or a fresh one if real-time play has not started.
+    pub fn get_clock(&self) -> GameClock {
+        self.get_resource::<GameClock>()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Moves the world forward by `dt`.
+    /// Periodic effects tick, creatures walk their movement orders as far as the time allows, and actions and cooldowns count down.
+    /// Every movement order is checked before anything changes, so a bad path returns an error and leaves the world as it was.
+    pub fn advance(&mut self, dt: Duration) -> SimutronResult<TimeReport> {
+        for creature in self.get_entities_with::<MovementOrder>() {
+            if let Some(order) = self.get_component::<MovementOrder>(creature) {
+                self.check_movement_path(creature, &order.path)?;
+            }
+        }
+
+        let mut clock = self.get_clock();
+        clock.elapsed += dt;
+        clock.tick_progress += dt;
+        let mut report = TimeReport {
+            elapsed: dt,
+            ..Default::default()
+        };
+        while !clock.turn_length.is_zero() && clock.tick_progress >= clock.turn_length {
+            clock.tick_progress -= clock.turn_length;
+            report.ticks += 1;
+        }
+        self.insert_resource(clock);
+        for _ in 0..report.ticks {
+            self.tick()?;
+        }
+
+        for creature in self.get_entities_with::<MovementOrder>() {
+            if let Some(movement) = self.follow_movement_order(creature, dt, &clock)? {
+                report.movements.push(movement);
+            }
+        }
+        for entity in self.get_entities_with::<ActionTimers>() {
+            let completed = self
+                .get_component_mut::<ActionTimers>(entity)
+                .and_then(|timers| timers.advance(dt));
+            if let Some(action) = completed {
+                debug!("{:?} completed {}", entity, action);
+                report.completed_actions.push((entity, action));
+            }
+        }
+        Ok(report)
+    }
+
+    /// Runs one tick of everything that happens on its own over time: poison spreads, infections worsen,
+    /// status effects run down, the dying bleed out and the resting recover. `advance` ticks once every turn length, and encounters tick once every round.
+    pub fn tick(&mut self) -> SimutronResult<()> {
+        let mut clock = self.get_clock();
+        clock.ticks += 1;
+        self.insert_resource(clock);
+        let cause = EffectCause {
+            tick: clock.ticks,
+            source: None,
+        };
+        for entity in self.get_entities_with::<Creature>() {
+            if let Some(creature) = self.get_component_mut::<Creature>(entity) {
+                creature.corpus.progress_wounds(clock.ticks);
+                if creature.get_vital_state() == VitalState::Dying {
+                    let vital = creature.get_vital_appendage();
+                    let bleed = vital.get_share_of_max(DYING_BLEED_PERCENT);
+                    let vital = vital.get_name().to_string();
+                    creature.corpus.apply_internal_effect_to(
+                        &vital,
+                        AppendageEffect::Abrasion,
+                        -bleed,
+                        cause,
+                    );
+                }
+            }
+            self.tick_status_effects(entity, clock.ticks)?;
+            self.regenerate(entity, clock.ticks)?;
+            self.sever_amputated(entity)?;
+            self.update_vital_state(entity)?;
+        }
+        Ok(())
+    }
+
+    /// Orders a creature to walk a path over time. Replaces any order the creature was following.
+    /// Each tile must be adjacent to the previous one, the same as `move_creature`.
+    pub fn order_movement(&mut self, creature: Entity, path: Vec<Position>) -> SimutronResult<()> {
+        if self.get_component::<Creature>(creature).is_none() {
+            return runtime_error!("Entity is not a creature.");
+        }
+        self.check_movement_path(creature, &path)?;
+        self.add_component(
+            creature,
+            MovementOrder {
+                path: path.into(),
+                ..Default::default()
+            },
+        );
+        Ok(())
+    }
+
+    /// Stops a creature where it stands. Any time banked towards the next step is lost.
+    pub fn cancel_movement(&mut self, creature: Entity) {
+        self.remove_component::<MovementOrder>(creature);
+    }
+
+    /// Starts an action that completes after `duration` and cannot be used again until `cooldown` has passed.
+    /// A creature can only do one timed action at a time.
+    pub fn start_action(
+        &mut self,
+        entity: Entity,
+        action: &str,
+        duration: Duration,
+        cooldown: Duration,
+    ) -> SimutronResult<()> {
+        if self.get_component::<ActionTimers>(entity).is_none() {
+            self.add_component(entity, ActionTimers::default());
+        }
+        let timers = match self.get_component_mut::<ActionTimers>(entity) {
+            Some(timers) => timers,
+            None => return runtime_error!("Entity {:?} has no action timers.", entity),
+        };
+        if let Some(current) = &timers.current {
+            return runtime_error!("{:?} is busy with {}.", entity, current.name);
+        }
+        if !timers.is_ready(action) {
+            return runtime_error!("{} is still on cooldown.", action);
+        }
+        timers.current = Some(TimedAction {
+            name: action.to_string(),
+            remaining: duration,
+            cooldown,
+        });
+        Ok(())
+    }
+
+    /// Checks that a creature can follow a path without `calculate_final_position` failing halfway.
+    /// The creature needs a position and a speed, and every tile up to the edge of the map must be on a known map
+    /// and adjacent to the one before it.
+    fn check_movement_path<'a>(
+        &self,
+        creature: Entity,
+        path: impl IntoIterator<Item = &'a Position>,
+    ) -> SimutronResult<()> {
+        let mut previous = match self.get_component::<Position>(creature) {
+            Some(position) => *position,
+            None => return runtime_error!("Creature has no position component."),
+        };
+        self.get_movement_speeds(creature)?;
+        for position in path {
+            if self.is_off_map(position) {
+                break;
+            }
+            let map = match position.map.and_then(|map_id| self.get_map(&map_id)) {
+                Some(map) => map,
+                None => return runtime_error!("Map not found for position {:?}.", position),
+            };
+            if !map
+                .get_topology()
+                .is_adjacent((previous.x, previous.y), (position.x, position.y))
+            {
+                return runtime_error!("Requested position is not adjacent to previous position.");
+            }
+            previous = *position;
+        }
+        Ok(())
+    }
+
+    /// Walks a creature along its movement order for `dt` worth of time.
+    fn follow_movement_order(
+        &mut self,
+        creature: Entity,
+        dt: Duration,
+        clock: &GameClock,
+    ) -> SimutronResult<Option<MovementReport>> {
+        let mut order = match self.get_component::<MovementOrder>(creature) {
+            Some(order) => order.clone(),
+            None => return Ok(None),
+        };
+        let position = match self.get_component::<Position>(creature) {
+            Some(position) => *position,
+            None => return runtime_error!("Creature has no position component."),
+        };
+        let speeds = self.get_movement_speeds(creature)?;
+        let speed = Self::get_budget_speed(&speeds);
+        order.progress += dt;
+
+        let report = self.calculate_final_position(
+            creature,
+            order.path.iter().copied().collect(),
+            &speeds,
+            clock.get_movement_for(order.progress, speed),
+            position,
+            order.diagonals_taken,
+        )?;
+        if let Some(destination) = report.get_final_position() {
+            self.set_position(creature, destination);
+        }
+        self.apply_fall_damage(&report)?;
+        if self.get_component::<Creature>(creature).is_none() {
+            // The landing was fatal and the creature became a corpse.
+            return Ok(Some(report));
+        }
+
+        let walked = report.steps.len();
+        order.path.drain(..walked);
+        order.diagonals_taken += report.steps.iter().filter(|step| step.diagonal).count() as u32;
+        let spent = clock.get_travel_time(report.spent, speed);
+        order.progress = order.progress.saturating_sub(spent.unwrap_or_default());
+
+        // A creature short on time keeps walking next time. Anything else ends the order.
+        if report.stop_reason == StopReason::OutOfMovement && spent.is_some() {
+            self.add_component(creature, order);
+            if walked == 0 {
+                return Ok(None);
+            }
+        } else {
+            self.remove_component::<MovementOrder>(creature);
+        }
+        Ok(Some(report))
+    }
+}
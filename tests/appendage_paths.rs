@@ -0,0 +1,92 @@
+mod common;
+
+use common::*;
+use simutron::creatures::morphologies::humanoid::humanoid_corpus;
+use simutron::prelude::*;
+
+fn scratch(world: &mut World, creature: Entity, target: &str) -> SimutronResult<Creature> {
+    world.apply_creature_action(&CreatureActions {
+        from: creature,
+        to: creature,
+        target: target.to_string(),
+        effect: AppendageEffect::Abrasion,
+        impact: -10,
+    })
+}
+
+#[test]
+fn test_lookup_by_name_path_and_id() {
+    let alice = Creature {
+        name: "Alice".to_string(),
+        corpus: humanoid_corpus(),
+    };
+    let hand = alice.find("Torso/Left Arm/Left Hand").unwrap();
+    assert_eq!(hand.get_name(), "Left Hand");
+    assert_eq!(
+        alice.find_by_id(hand.get_id()).unwrap().get_name(),
+        "Left Hand"
+    );
+    assert_eq!(
+        alice.get_path("Left Hand").as_deref(),
+        Some("Torso/Left Arm/Left Hand")
+    );
+    assert_eq!(alice.get_path("Torso").as_deref(), Some("Torso"));
+    assert_eq!(alice.parent_of("Left Hand").unwrap().get_name(), "Left Arm");
+    assert!(alice.parent_of("Torso").is_none());
+
+    // Paths have to be complete and correct.
+    assert!(alice.find("Left Arm/Left Hand").is_none());
+    assert!(alice.find("Torso/Right Arm/Left Hand").is_none());
+    assert!(alice.find("Left Hnad").is_none());
+
+    let names: Vec<_> = alice.iter().map(|appendage| appendage.get_name()).collect();
+    assert_eq!(names.len(), 10);
+    assert_eq!(&names[..3], ["Torso", "Left Arm", "Left Hand"]);
+    let mut ids: Vec<_> = alice.iter().map(|appendage| appendage.get_id()).collect();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 10);
+}
+
+#[test]
+fn test_ids_are_stable() {
+    let mut lizard = MorphologyBuilder::new("Body");
+    lizard.add_appendage("Body", "Leg").unwrap();
+    lizard.add_appendage("Body", "Tail").unwrap();
+    lizard.add_appendage("Leg", "Foot").unwrap();
+    let before = lizard.build();
+    lizard.rename_appendage("Leg", "Front Leg").unwrap();
+    lizard.move_appendage("Foot", "Tail").unwrap();
+    let after = lizard.build();
+
+    let foot = before.find("Body/Leg/Foot").unwrap().get_id();
+    assert_eq!(after.find("Body/Tail/Foot").unwrap().get_id(), foot);
+    assert_eq!(
+        after.find("Front Leg").unwrap().get_id(),
+        before.find("Leg").unwrap().get_id()
+    );
+    assert!(lizard.add_appendage("Body", "Left/Right").is_err());
+}
+
+#[test]
+fn test_actions_need_a_real_target() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    let body = scratch(&mut world, alice, "Torso/Right Arm").unwrap();
+    assert_eq!(body.find("Right Arm").unwrap().get_health(), 90);
+    assert!(scratch(&mut world, alice, "Tail").is_err());
+    assert!(scratch(&mut world, alice, "Torso/Left Hand").is_err());
+
+    // Statuses accept paths too.
+    world
+        .apply_status(
+            alice,
+            StatusEffect::new(StatusKind::Bleeding, 3).with_appendage("Torso/Left Leg"),
+        )
+        .unwrap();
+    let statuses = world.get_component::<StatusEffects>(alice).unwrap();
+    assert_eq!(
+        statuses.get_effects()[0].appendage.as_deref(),
+        Some("Left Leg")
+    );
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::*;
+use simutron::prelude::*;
+
+#[test]
+fn test_max_health_and_thresholds() {
+    let mut tortoise = MorphologyBuilder::new("Body");
+    tortoise
+        .add_appendage(
+            "Body",
+            AppendageSpec::new("Tail")
+                .with_max_health(50)
+                .with_thresholds(HealthThresholds {
+                    full: 99,
+                    wounded: 60,
+                    disabled: 40,
+                }),
+        )
+        .unwrap();
+    tortoise.add_appendage("Body", "Head").unwrap();
+    let mut world = World::new();
+    let tortoise = spawn_with(&mut world, "Tortoise", tortoise.build());
+
+    hit(&mut world, tortoise, "Tail", AppendageEffect::Abrasion, -15);
+    let tail = appendage(&world, tortoise, "Tail");
+    assert_eq!(tail.get_health(), 35);
+    assert_eq!(tail.get_health_percent(), 70);
+    assert_eq!(tail.get_state(), AppendageHealth::Wounded);
+
+    // The same blow barely scratches an appendage with default health.
+    hit(&mut world, tortoise, "Head", AppendageEffect::Abrasion, -15);
+    assert_eq!(
+        appendage(&world, tortoise, "Head").get_state(),
+        AppendageHealth::Wounded
+    );
+    hit(&mut world, tortoise, "Tail", AppendageEffect::Abrasion, -10);
+    assert_eq!(
+        appendage(&world, tortoise, "Tail").get_state(),
+        AppendageHealth::Disabled
+    );
+
+    // Healing never goes past the maximum.
+    hit(&mut world, tortoise, "Tail", AppendageEffect::Abrasion, 100);
+    assert_eq!(appendage(&world, tortoise, "Tail").get_health(), 50);
+}
+
+#[test]
+fn test_armor_and_resistances() {
+    let mut tortoise = MorphologyBuilder::new(
+        AppendageSpec::new("Shell")
+            .with_armor(5)
+            .with_resistance(AppendageEffect::Burn, 50)
+            .with_resistance(AppendageEffect::Crush, -100),
+    );
+    tortoise.add_appendage("Shell", "Head").unwrap();
+    let mut world = World::new();
+    let tortoise = spawn_with(&mut world, "Tortoise", tortoise.build());
+
+    hit(
+        &mut world,
+        tortoise,
+        "Shell",
+        AppendageEffect::Abrasion,
+        -20,
+    );
+    hit(&mut world, tortoise, "Shell", AppendageEffect::Burn, -20);
+    hit(&mut world, tortoise, "Shell", AppendageEffect::Crush, -10);
+    hit(&mut world, tortoise, "Shell", AppendageEffect::Poison, -10);
+    hit(&mut world, tortoise, "Shell", AppendageEffect::Abrasion, -3);
+
+    let shell = appendage(&world, tortoise, "Shell");
+    assert_eq!(shell.get_effect_impact(AppendageEffect::Abrasion), -15);
+    assert_eq!(shell.get_effect_impact(AppendageEffect::Burn), -7);
+    assert_eq!(shell.get_effect_impact(AppendageEffect::Crush), -10);
+    // Armor does nothing against poison.
+    assert_eq!(shell.get_effect_impact(AppendageEffect::Poison), -10);
+
+    // Neither armor nor resistances get in the way of healing.
+    hit(&mut world, tortoise, "Shell", AppendageEffect::Abrasion, 10);
+    let shell = appendage(&world, tortoise, "Shell");
+    assert_eq!(shell.get_effect_impact(AppendageEffect::Abrasion), -5);
+
+    // The head has none of the shell's protection.
+    hit(&mut world, tortoise, "Head", AppendageEffect::Burn, -20);
+    let head = appendage(&world, tortoise, "Head");
+    assert_eq!(head.get_effect_impact(AppendageEffect::Burn), -20);
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use common::*;
+use simutron::prelude::*;
+
+fn crush(world: &mut World, creature: Entity, target: &str) {
+    world
+        .apply_creature_action(&CreatureActions {
+            from: creature,
+            to: creature,
+            target: target.to_string(),
+            effect: AppendageEffect::Crush,
+            impact: -40,
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_disabled_feet_slow_movement() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    assert_eq!(world.get_remaining_movement(alice).unwrap(), 30);
+
+    crush(&mut world, alice, "Left Foot");
+    assert_eq!(world.get_remaining_movement(alice).unwrap(), 15);
+
+    // A broken leg takes the foot with it. Flying does not need feet.
+    world.add_component(
+        alice,
+        MovementModes::new().with_speed(MovementMode::Fly, 20),
+    );
+    crush(&mut world, alice, "Right Leg");
+    let body = world.get_component::<Creature>(alice).unwrap();
+    assert_eq!(body.get_capability_ratio(Capability::Locomotion), 0.0);
+    assert_eq!(world.get_remaining_movement(alice).unwrap(), 20);
+}
+
+#[test]
+fn test_no_hands_no_items() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    let jar = world.create_prop("Jar", "A clay jar.");
+    let inspect = PropAction {
+        from: alice,
+        to: jar,
+        effect: PropEffect::Inspect,
+        impact: 0,
+    };
+    let smash = PropAction {
+        from: alice,
+        to: jar,
+        effect: PropEffect::Damage,
+        impact: 1,
+    };
+
+    crush(&mut world, alice, "Left Hand");
+    world.give_item(alice, alice, jar.0).unwrap();
+    crush(&mut world, alice, "Right Arm");
+    assert!(world.take_item(alice, alice, jar.0).is_err());
+    assert!(world.apply_prop_action(&smash).is_err());
+    // Looking needs no hands.
+    world.apply_prop_action(&inspect).unwrap();
+
+    // Someone else can still take the jar off her.
+    let bob = spawn(&mut world, "Bob");
+    world.take_item(bob, alice, jar.0).unwrap();
+    assert!(!world
+        .get_component::<Creature>(alice)
+        .unwrap()
+        .is_capable(Capability::Grasp));
+}
+
+#[test]
+fn test_losing_a_vital_appendage_kills() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    crush(&mut world, alice, "Left Arm");
+    assert!(world.get_component::<Creature>(alice).unwrap().is_alive());
+    crush(&mut world, alice, "Head");
+    let body = world.get_component::<Creature>(alice).unwrap();
+    assert!(!body.is_alive());
+    assert!(!body.is_capable(Capability::Sight));
+    assert!(body.get_character_health() > 50);
+}
+
+#[test]
+fn test_undeclared_capabilities_do_not_limit() {
+    let mut blob = MorphologyBuilder::new("Blob");
+    blob.add_appendage("Blob", "Pseudopod").unwrap();
+    let mut world = World::new();
+    let blob = spawn_with(&mut world, "Blob", blob.build());
+    let pebble = world.create_prop("Pebble", "A smooth pebble.");
+
+    crush(&mut world, blob, "Pseudopod");
+    assert_eq!(world.get_remaining_movement(blob).unwrap(), 30);
+    world.give_item(blob, blob, pebble.0).unwrap();
+    assert!(world.get_component::<Creature>(blob).unwrap().is_alive());
+}
@@ -0,0 +1,159 @@
+mod common;
+
+use common::*;
+use simutron::prelude::*;
+
+fn fighter(world: &mut World, name: &str, strength: u8, dexterity: u8) -> Entity {
+    let fighter = spawn(world, name);
+    world.add_component(
+        fighter,
+        CreatureSheet {
+            strength,
+            dexterity,
+            ..sheet()
+        },
+    );
+    fighter
+}
+
+#[test]
+fn test_attack_rolls_against_defense() {
+    let mut world = World::new();
+    world.seed_dice(5);
+    // Strength 16 adds 3, dexterity 14 makes the defense 12.
+    let alice = fighter(&mut world, "Alice", 16, 10);
+    let bob = fighter(&mut world, "Bob", 10, 14);
+    let attack = Attack::new(alice, bob, "Left Arm", "1d8");
+
+    for _ in 0..100 {
+        let entry = world.resolve_attack(&attack).unwrap();
+        let natural = entry.attack_roll.total;
+        assert_eq!(entry.defense, 12);
+        // Arms make up a quarter as much of the body as the torso, which costs 4 to aim at.
+        assert_eq!(entry.called_shot_penalty, 4);
+        assert_eq!(entry.attack_total, natural + 3 - 4);
+        let expected = match natural {
+            20 => AttackOutcome::CriticalHit,
+            1 => AttackOutcome::CriticalMiss,
+            _ if natural + 3 - 4 >= 12 => AttackOutcome::Hit,
+            _ => AttackOutcome::Miss,
+        };
+        assert_eq!(entry.outcome, expected);
+
+        match (&entry.damage_roll, &entry.action) {
+            (Some(damage), Some(action)) => {
+                let dice = if expected == AttackOutcome::CriticalHit {
+                    2
+                } else {
+                    1
+                };
+                assert_eq!(damage.dice.len(), dice);
+                assert_eq!(action.impact, -(damage.total + 3));
+                assert_eq!(action.target, "Left Arm");
+                assert_eq!(action.to, bob);
+            }
+            (None, None) => assert!(!expected.is_hit()),
+            _ => panic!("A hit needs both a damage roll and an action."),
+        }
+    }
+    assert_eq!(
+        world.get_resource::<CombatLog>().unwrap().entries.len(),
+        100
+    );
+}
+
+#[test]
+fn test_natural_rolls_override_modifiers() {
+    let mut world = World::new();
+    world.seed_dice(11);
+    let alice = fighter(&mut world, "Alice", 10, 10);
+    let bob = fighter(&mut world, "Bob", 10, 10);
+    let hopeless = Attack::new(alice, bob, "Torso", "1d4").with_modifiers(-100, 0);
+    let certain = Attack::new(alice, bob, "Torso", "1d4").with_modifiers(100, 0);
+
+    for _ in 0..200 {
+        let entry = world.resolve_attack(&hopeless).unwrap();
+        assert_eq!(
+            entry.outcome.is_hit(),
+            entry.outcome == AttackOutcome::CriticalHit
+        );
+        let entry = world.resolve_attack(&certain).unwrap();
+        assert_eq!(
+            entry.outcome.is_hit(),
+            entry.outcome != AttackOutcome::CriticalMiss
+        );
+    }
+}
+
+#[test]
+fn test_weak_hits_still_hurt() {
+    let mut world = World::new();
+    world.seed_dice(3);
+    let alice = fighter(&mut world, "Alice", 10, 1);
+    let bob = fighter(&mut world, "Bob", 10, 10);
+    let attack = Attack::new(alice, bob, "Head", "1d4")
+        .with_ability(AttackAbility::Dexterity)
+        .with_roll_mode(RollMode::Advantage)
+        .with_modifiers(100, -100);
+
+    let entry = world.perform_attack(&attack).unwrap();
+    assert_eq!(entry.attack_roll.dice.len(), 2);
+    if let Some(action) = entry.action {
+        assert_eq!(action.impact, -1);
+        let bob = world.get_component::<Creature>(bob).unwrap();
+        assert!(bob.get_character_health() < 100);
+    }
+}
+
+#[test]
+fn test_huge_modifiers_saturate() {
+    let mut world = World::new();
+    world.seed_dice(6);
+    let alice = fighter(&mut world, "Alice", 20, 10);
+    let bob = fighter(&mut world, "Bob", 10, 10);
+    let strong = Attack::new(alice, bob, "Torso", "1d4").with_modifiers(i32::MAX, i32::MAX);
+    let weak = Attack::new(alice, bob, "Left Foot", "1d4").with_modifiers(i32::MIN, i32::MIN);
+    for _ in 0..10 {
+        let entry = world.resolve_attack(&strong).unwrap();
+        assert_eq!(entry.attack_total, i32::MAX);
+        if let Some(action) = entry.action {
+            assert_eq!(action.impact, -i32::MAX);
+        }
+        let entry = world.resolve_attack(&weak).unwrap();
+        assert!(entry.attack_total < i32::MIN + 30);
+        if let Some(action) = entry.action {
+            assert_eq!(action.impact, -1);
+        }
+    }
+}
+
+#[test]
+fn test_attacks_are_reproducible() {
+    let outcomes = |seed: u64| {
+        let mut world = World::new();
+        world.seed_dice(seed);
+        let alice = fighter(&mut world, "Alice", 12, 12);
+        let bob = fighter(&mut world, "Bob", 12, 12);
+        let attack = Attack::new(alice, bob, "Torso", "2d6+1");
+        (0..20)
+            .map(|_| {
+                let entry = world.perform_attack(&attack).unwrap();
+                (entry.attack_total, entry.action.map(|action| action.impact))
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(outcomes(8), outcomes(8));
+}
+
+#[test]
+fn test_attacks_need_creatures() {
+    let mut world = World::new();
+    let alice = fighter(&mut world, "Alice", 10, 10);
+    let rock = world.create_prop("Rock", "A rock.");
+    assert!(world
+        .resolve_attack(&Attack::new(alice, rock, "Torso", "1d4"))
+        .is_err());
+    assert!(world
+        .resolve_attack(&Attack::new(alice, alice, "Torso", "banana"))
+        .is_err());
+}
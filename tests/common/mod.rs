@@ -0,0 +1,69 @@
+// Each test crate only uses some of these.
+#![allow(dead_code)]
+
+use simutron::creatures::morphologies::humanoid::humanoid_corpus;
+use simutron::prelude::*;
+
+/// An unremarkable creature sheet: speed 30 and 10 in everything else.
+pub fn sheet() -> CreatureSheet {
+    CreatureSheet {
+        speed: 30,
+        strength: 10,
+        intelligence: 10,
+        dexterity: 10,
+        constitution: 10,
+        wisdom: 10,
+        charisma: 10,
+    }
+}
+
+/// Spawns a humanoid with an unremarkable sheet and empty pockets.
+pub fn spawn(world: &mut World, name: &str) -> Entity {
+    spawn_with(world, name, humanoid_corpus())
+}
+
+/// Spawns a creature with the given body, an unremarkable sheet and empty pockets.
+pub fn spawn_with(world: &mut World, name: &str, corpus: Appendage) -> Entity {
+    let creature = world.create_creature(Creature {
+        name: name.to_string(),
+        corpus,
+    });
+    world.add_component(creature, sheet());
+    world.add_component(creature, Inventory::new());
+    creature
+}
+
+/// The creature does something to one of its own appendages.
+pub fn hit(
+    world: &mut World,
+    creature: Entity,
+    target: &str,
+    effect: AppendageEffect,
+    impact: Health,
+) {
+    hit_by(world, creature, creature, target, effect, impact);
+}
+
+pub fn hit_by(
+    world: &mut World,
+    from: Entity,
+    to: Entity,
+    target: &str,
+    effect: AppendageEffect,
+    impact: Health,
+) {
+    world
+        .apply_creature_action(&CreatureActions {
+            from,
+            to,
+            target: target.to_string(),
+            effect,
+            impact,
+        })
+        .unwrap();
+}
+
+pub fn appendage(world: &World, creature: Entity, name: &str) -> Appendage {
+    let creature = world.get_component::<Creature>(creature).unwrap();
+    creature.corpus.find(name).unwrap().clone()
+}
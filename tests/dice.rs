@@ -0,0 +1,132 @@
+use simutron::creatures::morphologies::humanoid::humanoid_corpus;
+use simutron::prelude::*;
+
+#[test]
+fn test_parse_dice_expressions() {
+    let expression = DiceExpression::parse("2d6 + 3").unwrap();
+    assert_eq!(
+        expression.terms,
+        vec![
+            DiceTerm::Dice {
+                count: 2,
+                sides: 6,
+                keep: Keep::All,
+                negative: false,
+            },
+            DiceTerm::Modifier(3),
+        ]
+    );
+    assert_eq!(expression.to_string(), "2d6+3");
+    assert_eq!(expression.get_range(), (5, 15));
+
+    assert_eq!(
+        DiceExpression::parse("4d6kh3").unwrap().to_string(),
+        "4d6kh3"
+    );
+    assert_eq!(
+        DiceExpression::parse("D20adv").unwrap().to_string(),
+        "2d20kh1"
+    );
+    assert_eq!(
+        DiceExpression::parse("d20dis-1").unwrap().to_string(),
+        "2d20kl1-1"
+    );
+    assert_eq!(
+        DiceExpression::parse("-1d4+2").unwrap().get_range(),
+        (-2, 1)
+    );
+
+    for broken in ["", "2d", "2d6+", "d6++1", "3x6", "0d6", "2d6kx1"] {
+        assert!(DiceExpression::parse(broken).is_err(), "{broken}");
+    }
+}
+
+#[test]
+fn test_huge_expressions_are_rejected_or_clamped() {
+    for huge in [
+        "4000000000d6",
+        "3000000000d20adv",
+        "501d20adv",
+        "1d4294967295",
+    ] {
+        assert!(DiceExpression::parse(huge).is_err(), "{huge}");
+    }
+    assert!(DiceExpression::parse("1000d1000").is_ok());
+    assert!(DiceExpression::parse("500d20adv").is_ok());
+
+    // Flat modifiers can still push totals past what an i32 holds.
+    let expression = DiceExpression::parse("2147483647+1000d1000").unwrap();
+    assert_eq!(expression.get_range(), (i32::MAX, i32::MAX));
+    let roll = Dice::new(7).roll_expression(&expression);
+    assert_eq!(roll.total, i32::MAX);
+}
+
+#[test]
+fn test_rolls_stay_in_range() {
+    let mut dice = Dice::new(7);
+    for expression in ["1d20", "2d6+3", "4d6kh3", "1d20dis", "1d8-1d4"] {
+        let (low, high) = DiceExpression::parse(expression).unwrap().get_range();
+        for _ in 0..200 {
+            let roll = dice.roll(expression).unwrap();
+            assert!((low..=high).contains(&roll.total), "{expression}");
+        }
+    }
+
+    let roll = dice.roll("4d6kh3").unwrap();
+    assert_eq!(roll.dice.len(), 4);
+    assert_eq!(roll.dice.iter().filter(|die| die.kept).count(), 3);
+    let dropped = roll.dice.iter().find(|die| !die.kept).unwrap();
+    assert!(roll.dice.iter().all(|die| die.value >= dropped.value));
+}
+
+#[test]
+fn test_rolls_are_reproducible_and_logged() {
+    let mut first = Dice::new(1234);
+    let mut second = Dice::new(1234);
+    let rolls: Vec<i32> = (0..20)
+        .map(|_| first.roll("3d6+1").unwrap().total)
+        .collect();
+    let replay: Vec<i32> = (0..20)
+        .map(|_| second.roll("3d6+1").unwrap().total)
+        .collect();
+    assert_eq!(rolls, replay);
+
+    assert_eq!(first.get_seed(), 1234);
+    assert_eq!(first.get_log().len(), 20);
+    assert_eq!(first.get_log()[0].expression, "3d6+1");
+    assert_eq!(first.take_log().len(), 20);
+    assert!(first.get_log().is_empty());
+
+    // The log keeps the expression as it was written.
+    assert_eq!(first.roll("1d20adv").unwrap().expression, "1d20adv");
+}
+
+#[test]
+fn test_seeded_world_initiative_is_reproducible() {
+    let initiative = |seed: u64| {
+        let mut world = World::new();
+        world.seed_dice(seed);
+        let fighters: Vec<Entity> = ["Alice", "Bob", "Carol"]
+            .iter()
+            .map(|name| {
+                world.create_creature(Creature {
+                    name: name.to_string(),
+                    corpus: humanoid_corpus(),
+                })
+            })
+            .collect();
+        world.start_encounter(&fighters).unwrap();
+        let order = world.get_resource::<Encounter>().unwrap().get_turn_order();
+        let log = world.get_resource::<Dice>().unwrap().get_log().len();
+        (
+            order
+                .iter()
+                .map(|combatant| combatant.initiative)
+                .collect::<Vec<_>>(),
+            log,
+        )
+    };
+    let (first, rolls) = initiative(99);
+    assert_eq!(first, initiative(99).0);
+    assert_eq!(rolls, 3);
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use common::*;
+use simutron::creatures::creature_builder::TIMELINE_LENGTH;
+use simutron::prelude::*;
+
+#[test]
+fn test_timeline_keeps_order_and_sources() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    let bob = spawn(&mut world, "Bob");
+    let carol = spawn(&mut world, "Carol");
+
+    hit_by(
+        &mut world,
+        alice,
+        bob,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        -20,
+    );
+    world.tick().unwrap();
+    hit_by(
+        &mut world,
+        alice,
+        bob,
+        "Left Arm",
+        AppendageEffect::Crush,
+        -40,
+    );
+    hit_by(
+        &mut world,
+        carol,
+        bob,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        5,
+    );
+
+    let arm = appendage(&world, bob, "Left Arm");
+    let timeline: Vec<_> = arm
+        .get_timeline()
+        .map(|record| {
+            (
+                record.tick,
+                record.source,
+                record.effect,
+                record.impact,
+                record.state,
+            )
+        })
+        .collect();
+    assert_eq!(
+        timeline,
+        vec![
+            (
+                0,
+                Some(alice),
+                AppendageEffect::Abrasion,
+                -20,
+                AppendageHealth::Wounded
+            ),
+            (
+                1,
+                Some(alice),
+                AppendageEffect::Crush,
+                -40,
+                AppendageHealth::Disabled
+            ),
+            (
+                1,
+                Some(carol),
+                AppendageEffect::Abrasion,
+                5,
+                AppendageHealth::Disabled
+            ),
+        ]
+    );
+    assert_eq!(arm.get_timeline_from(alice).count(), 2);
+    assert_eq!(arm.get_timeline_since(1).count(), 2);
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Abrasion), -15);
+    assert_eq!(arm.get_health(), 45);
+
+    // The hand was only disabled along with the arm, so nothing landed on it.
+    assert_eq!(
+        appendage(&world, bob, "Left Hand").get_timeline().count(),
+        0
+    );
+}
+
+#[test]
+fn test_progressing_wounds_have_no_source() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    hit_by(
+        &mut world,
+        alice,
+        alice,
+        "Left Leg",
+        AppendageEffect::Infect,
+        -5,
+    );
+    world.tick().unwrap();
+
+    let leg = appendage(&world, alice, "Left Leg");
+    let last = leg.get_timeline().last().unwrap();
+    assert_eq!(last.tick, 1);
+    assert_eq!(last.source, None);
+    assert_eq!(last.effect, AppendageEffect::Infect);
+    assert_eq!(last.impact, -2);
+}
+
+#[test]
+fn test_timeline_is_bounded() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    for _ in 0..TIMELINE_LENGTH + 6 {
+        hit_by(
+            &mut world,
+            alice,
+            alice,
+            "Torso",
+            AppendageEffect::Abrasion,
+            -1,
+        );
+    }
+
+    let torso = appendage(&world, alice, "Torso");
+    assert_eq!(torso.get_timeline().count(), TIMELINE_LENGTH);
+    // The aggregate still counts every hit.
+    assert_eq!(
+        torso.get_effect_impact(AppendageEffect::Abrasion),
+        -(TIMELINE_LENGTH as Health + 6)
+    );
+}
@@ -0,0 +1,208 @@
+mod common;
+
+use common::*;
+use simutron::prelude::*;
+use std::time::Duration;
+
+const HUMANOID_APPENDAGES: [&str; 10] = [
+    "Torso",
+    "Left Arm",
+    "Right Arm",
+    "Left Hand",
+    "Right Hand",
+    "Left Leg",
+    "Right Leg",
+    "Left Foot",
+    "Right Foot",
+    "Head",
+];
+
+fn fighter(world: &mut World, name: &str, dexterity: u8) -> Entity {
+    let fighter = spawn(world, name);
+    world.add_component(
+        fighter,
+        CreatureSheet {
+            dexterity,
+            ..sheet()
+        },
+    );
+    fighter
+}
+
+fn kill(world: &mut World, creature: Entity) {
+    for appendage in HUMANOID_APPENDAGES {
+        // Severed appendages can no longer be hit.
+        let body = world.get_component::<Creature>(creature).unwrap();
+        if body.find(appendage).is_none() {
+            continue;
+        }
+        world
+            .apply_creature_action(&CreatureActions {
+                from: creature,
+                to: creature,
+                target: appendage.to_string(),
+                effect: AppendageEffect::Abrasion,
+                impact: -100,
+            })
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_initiative_uses_dexterity() {
+    let mut world = World::new();
+    world.seed_dice(1);
+    let nimble = fighter(&mut world, "Nimble", 30);
+    let clumsy = fighter(&mut world, "Clumsy", 1);
+    world.start_encounter(&[clumsy, nimble]).unwrap();
+
+    // +10 against -5, so the clumsy one needs a far better roll to go first.
+    let order = world.get_resource::<Encounter>().unwrap().get_turn_order();
+    assert_eq!(order[0].entity, nimble);
+    assert_eq!(order[1].entity, clumsy);
+    assert!((11..=30).contains(&order[0].initiative));
+    assert!((-4..=15).contains(&order[1].initiative));
+    assert_eq!(world.current_actor(), Some(nimble));
+}
+
+#[test]
+fn test_turns_and_rounds() {
+    let mut world = World::new();
+    let alice = fighter(&mut world, "Alice", 10);
+    let bob = fighter(&mut world, "Bob", 10);
+    let carol = fighter(&mut world, "Carol", 10);
+    world
+        .start_encounter_with_initiative(&[(alice, 12), (bob, 18), (carol, 12)])
+        .unwrap();
+
+    // Ties go to whoever was seated first.
+    assert_eq!(world.current_actor(), Some(bob));
+    assert_eq!(world.end_turn().unwrap(), Some(alice));
+    assert_eq!(world.end_turn().unwrap(), Some(carol));
+    assert_eq!(world.get_resource::<Encounter>().unwrap().get_round(), 1);
+    assert_eq!(world.end_turn().unwrap(), Some(bob));
+    assert_eq!(world.get_resource::<Encounter>().unwrap().get_round(), 2);
+
+    let encounter = world.end_encounter().unwrap();
+    assert_eq!(encounter.get_round(), 2);
+    assert_eq!(world.current_actor(), None);
+    assert!(world.end_turn().is_err());
+}
+
+#[test]
+fn test_turn_start_refills_movement() {
+    let mut world = World::new();
+    let alice = fighter(&mut world, "Alice", 10);
+    let bob = fighter(&mut world, "Bob", 10);
+    world
+        .start_encounter_with_initiative(&[(alice, 15), (bob, 5)])
+        .unwrap();
+
+    world.add_component(
+        alice,
+        MovementBudget {
+            spent: 30,
+            dashes: 1,
+        },
+    );
+    world.end_turn().unwrap();
+    world.end_turn().unwrap();
+    assert_eq!(
+        world.get_component::<MovementBudget>(alice),
+        Some(&MovementBudget::new())
+    );
+}
+
+#[test]
+fn test_rounds_pause_real_time_orders() {
+    let mut world = World::new();
+    let trail = ForestBuilder::new(5, 1, 5, Tile::new(ForestMaterial::Soil)).build();
+    world.add_map(trail.clone());
+    let at = |x| Position {
+        map: Some(trail.id),
+        x,
+        y: 0,
+        z: 0,
+    };
+    let alice = fighter(&mut world, "Alice", 10);
+    let bob = fighter(&mut world, "Bob", 10);
+    world.teleport_creature(alice, at(0)).unwrap();
+    world.order_movement(alice, vec![at(1), at(2)]).unwrap();
+    world
+        .start_action(alice, "Reload", Duration::from_secs(6), Duration::ZERO)
+        .unwrap();
+
+    world
+        .start_encounter_with_initiative(&[(alice, 15), (bob, 5)])
+        .unwrap();
+    for _ in 0..4 {
+        world.end_turn().unwrap();
+    }
+    assert_eq!(world.get_clock().ticks, 2);
+    assert_eq!(world.get_component::<Position>(alice).unwrap().x, 0);
+    assert!(world.get_component::<MovementOrder>(alice).is_some());
+    assert!(world
+        .get_component::<ActionTimers>(alice)
+        .unwrap()
+        .is_busy());
+}
+
+#[test]
+fn test_joining_and_leaving_mid_encounter() {
+    let mut world = World::new();
+    let alice = fighter(&mut world, "Alice", 10);
+    let bob = fighter(&mut world, "Bob", 10);
+    let carol = fighter(&mut world, "Carol", 10);
+    world
+        .start_encounter_with_initiative(&[(alice, 15), (bob, 5)])
+        .unwrap();
+    world.end_turn().unwrap();
+    assert_eq!(world.current_actor(), Some(bob));
+
+    // Carol beats Bob's initiative, but joining does not take the turn away from him.
+    world.join_encounter_with_initiative(carol, 10).unwrap();
+    assert!(world.join_encounter_with_initiative(carol, 10).is_err());
+    assert_eq!(world.current_actor(), Some(bob));
+
+    // Bob walks away on his own turn, which ends the round.
+    assert_eq!(world.leave_encounter(bob).unwrap(), Some(alice));
+    assert_eq!(world.get_resource::<Encounter>().unwrap().get_round(), 2);
+    assert_eq!(world.end_turn().unwrap(), Some(carol));
+    assert!(world.leave_encounter(bob).is_err());
+}
+
+#[test]
+fn test_creatures_take_one_seat_each() {
+    let mut world = World::new();
+    let alice = fighter(&mut world, "Alice", 10);
+    let bob = fighter(&mut world, "Bob", 10);
+    world
+        .start_encounter_with_initiative(&[(alice, 15), (bob, 10)])
+        .unwrap();
+    assert!(world
+        .start_encounter_with_initiative(&[(alice, 15), (bob, 10), (alice, 5)])
+        .is_err());
+    assert!(world.start_encounter(&[bob, bob]).is_err());
+
+    // The encounter already running is left alone.
+    let encounter = world.get_resource::<Encounter>().unwrap();
+    assert_eq!(encounter.get_turn_order().len(), 2);
+    assert_eq!(world.current_actor(), Some(alice));
+}
+
+#[test]
+fn test_dying_creatures_leave_the_turn_order() {
+    let mut world = World::new();
+    let alice = fighter(&mut world, "Alice", 10);
+    let bob = fighter(&mut world, "Bob", 10);
+    let carol = fighter(&mut world, "Carol", 10);
+    world
+        .start_encounter_with_initiative(&[(alice, 15), (bob, 10), (carol, 5)])
+        .unwrap();
+
+    kill(&mut world, bob);
+    assert_eq!(world.end_turn().unwrap(), Some(carol));
+    let encounter = world.get_resource::<Encounter>().unwrap();
+    assert!(!encounter.contains(bob));
+    assert_eq!(encounter.get_turn_order().len(), 2);
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use common::*;
+use simutron::prelude::*;
+
+fn give(
+    world: &mut World,
+    creature: Entity,
+    name: &str,
+    slot: EquipmentSlot,
+    armor: Health,
+) -> Entity {
+    let item = world.create_prop(name, "Something to wear.");
+    world.add_component(item, Equippable { slot, armor });
+    world.add_to_inventory(creature, item.0).unwrap();
+    item
+}
+
+fn carried(world: &World, holder: Entity) -> Vec<Entity> {
+    let inventory = world.get_component::<Inventory>(holder).unwrap();
+    inventory.items.iter().map(|item| Entity(*item)).collect()
+}
+
+#[test]
+fn test_equip_and_unequip() {
+    let mut world = World::new();
+    let knight = spawn(&mut world, "Knight");
+    let helmet = give(&mut world, knight, "Helmet", EquipmentSlot::Helmet, 3);
+    let sword = give(&mut world, knight, "Sword", EquipmentSlot::Weapon, 0);
+    let dagger = give(&mut world, knight, "Dagger", EquipmentSlot::Weapon, 0);
+
+    // Only appendages with the right slot take an item.
+    assert!(world.equip(knight, helmet, "Torso").is_err());
+    assert!(world.equip(knight, helmet, "Tail").is_err());
+    world.equip(knight, helmet, "Head").unwrap();
+    world.equip(knight, sword, "Right Hand").unwrap();
+    assert!(world.equip(knight, dagger, "Right Hand").is_err());
+    world
+        .equip(knight, dagger, "Torso/Left Arm/Left Hand")
+        .unwrap();
+    assert!(carried(&world, knight).is_empty());
+    // Items have to be carried to be equipped.
+    assert!(world.equip(knight, sword, "Left Hand").is_err());
+
+    let body = world.get_component::<Creature>(knight).unwrap().clone();
+    let equipment = world.get_component::<Equipment>(knight).unwrap();
+    let hand = body.find("Right Hand").unwrap().get_id();
+    assert_eq!(equipment.get(hand, EquipmentSlot::Weapon), Some(sword));
+    assert_eq!(equipment.get_items().count(), 3);
+
+    assert_eq!(
+        world
+            .unequip(knight, "Right Hand", EquipmentSlot::Weapon)
+            .unwrap(),
+        sword
+    );
+    assert!(world
+        .unequip(knight, "Right Hand", EquipmentSlot::Weapon)
+        .is_err());
+    assert_eq!(carried(&world, knight), vec![sword]);
+}
+
+#[test]
+fn test_broken_appendages_cannot_be_equipped() {
+    let mut world = World::new();
+    let knight = spawn(&mut world, "Knight");
+    let boot = give(&mut world, knight, "Boot", EquipmentSlot::Boots, 1);
+    hit(&mut world, knight, "Left Leg", AppendageEffect::Crush, -40);
+    assert!(world.equip(knight, boot, "Left Foot").is_err());
+    world.equip(knight, boot, "Right Foot").unwrap();
+
+    // Without hands there is nothing to put gear on with.
+    let helmet = give(&mut world, knight, "Helmet", EquipmentSlot::Helmet, 3);
+    hit(&mut world, knight, "Left Arm", AppendageEffect::Crush, -40);
+    hit(&mut world, knight, "Right Arm", AppendageEffect::Crush, -40);
+    assert!(world.equip(knight, helmet, "Head").is_err());
+}
+
+#[test]
+fn test_armor_softens_blows() {
+    let mut world = World::new();
+    let knight = spawn(&mut world, "Knight");
+    let mail = give(&mut world, knight, "Mail", EquipmentSlot::Armor, 4);
+    world.equip(knight, mail, "Torso").unwrap();
+
+    hit(&mut world, knight, "Torso", AppendageEffect::Abrasion, -10);
+    hit(&mut world, knight, "Torso", AppendageEffect::Crush, -3);
+    hit(&mut world, knight, "Torso", AppendageEffect::Poison, -5);
+    let body = world.get_component::<Creature>(knight).unwrap();
+    let torso = body.find("Torso").unwrap();
+    assert_eq!(torso.get_effect_impact(AppendageEffect::Abrasion), -6);
+    assert_eq!(torso.get_effect_impact(AppendageEffect::Crush), 0);
+    assert_eq!(torso.get_effect_impact(AppendageEffect::Poison), -5);
+    assert_eq!(world.get_equipped_armor(knight, torso.get_id()), 4);
+
+    // The arms are not covered.
+    hit(
+        &mut world,
+        knight,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        -10,
+    );
+    let body = world.get_component::<Creature>(knight).unwrap();
+    let arm = body.find("Left Arm").unwrap();
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Abrasion), -10);
+}
+
+#[test]
+fn test_gear_goes_with_severed_appendages_and_corpses() {
+    let mut world = World::new();
+    world.insert_resource(DeathRules {
+        leave_corpses: true,
+    });
+    let knight = spawn(&mut world, "Knight");
+    let sword = give(&mut world, knight, "Sword", EquipmentSlot::Weapon, 0);
+    let helmet = give(&mut world, knight, "Helmet", EquipmentSlot::Helmet, 0);
+    world.equip(knight, sword, "Right Hand").unwrap();
+    world.equip(knight, helmet, "Head").unwrap();
+
+    hit(
+        &mut world,
+        knight,
+        "Right Arm",
+        AppendageEffect::Abrasion,
+        -100,
+    );
+    let arm = world
+        .get_entities_with::<SeveredAppendage>()
+        .into_iter()
+        .next()
+        .unwrap();
+    assert_eq!(carried(&world, arm), vec![sword]);
+    let equipment = world.get_component::<Equipment>(knight).unwrap();
+    assert_eq!(equipment.get_items().count(), 1);
+
+    hit(&mut world, knight, "Head", AppendageEffect::Crush, -40);
+    let corpse = world.get_entities_with::<Corpse>()[0];
+    assert_eq!(carried(&world, corpse), vec![helmet]);
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use common::*;
+use simutron::prelude::*;
+
+fn give_supply(world: &mut World, creature: Entity, method: HealingMethod, uses: u32) -> Entity {
+    let kit = world.create_prop("Kit", "A bag of medical supplies.");
+    world.add_component(kit, MedicalSupply { method, uses });
+    world.add_to_inventory(creature, kit.0).unwrap();
+    kit
+}
+
+#[test]
+fn test_first_aid_mends_by_wound_type() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    let medic = spawn(&mut world, "Medic");
+    let medkit = give_supply(&mut world, medic, HealingMethod::FirstAid, 2);
+    hit(
+        &mut world,
+        patient,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        -20,
+    );
+    hit(&mut world, patient, "Left Arm", AppendageEffect::Burn, -10);
+    hit(&mut world, patient, "Left Arm", AppendageEffect::Crush, -10);
+    let bandage = Healing::new(medic, patient, "Left Arm", HealingMethod::FirstAid);
+
+    // The worst wound is seen to first.
+    assert_eq!(world.heal(&bandage).unwrap(), 15);
+    let arm = appendage(&world, patient, "Left Arm");
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Abrasion), -5);
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Burn), -10);
+    assert_eq!(
+        world.get_component::<MedicalSupply>(medkit).unwrap().uses,
+        1
+    );
+
+    // Burns heal at half the rate, and first aid does nothing for crushed bones.
+    assert_eq!(world.heal(&bandage).unwrap(), 10);
+    let arm = appendage(&world, patient, "Left Arm");
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Abrasion), 0);
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Burn), -5);
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Crush), -10);
+    assert_eq!(arm.get_timeline_from(medic).count(), 3);
+
+    // The medkit is used up and thrown away.
+    assert!(world.get_component::<MedicalSupply>(medkit).is_none());
+    assert!(world
+        .get_component::<Inventory>(medic)
+        .unwrap()
+        .items
+        .is_empty());
+    assert!(world.heal(&bandage).is_err());
+}
+
+#[test]
+fn test_disabled_appendages_are_beyond_healing() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    let medic = spawn(&mut world, "Medic");
+    give_supply(&mut world, medic, HealingMethod::Surgery, 1);
+    hit(
+        &mut world,
+        patient,
+        "Right Arm",
+        AppendageEffect::Crush,
+        -40,
+    );
+
+    for method in [HealingMethod::Surgery, HealingMethod::Magic] {
+        let healing = Healing::new(medic, patient, "Right Arm", method);
+        assert!(world.heal(&healing).is_err());
+    }
+    // Nothing was used up on the attempt, and nothing to mend uses nothing either.
+    let healing = Healing::new(medic, patient, "Left Arm", HealingMethod::Surgery);
+    assert_eq!(world.heal(&healing).unwrap(), 0);
+    assert_eq!(world.get_entities_with::<MedicalSupply>().len(), 1);
+
+    // First aid needs supplies of its own.
+    hit(
+        &mut world,
+        patient,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        -10,
+    );
+    let healing = Healing::new(medic, patient, "Left Arm", HealingMethod::FirstAid);
+    assert!(world.heal(&healing).is_err());
+}
+
+#[test]
+fn test_surgery_and_magic_treat_progressing_wounds() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    let medic = spawn(&mut world, "Medic");
+    give_supply(&mut world, medic, HealingMethod::Surgery, 1);
+    hit(&mut world, patient, "Left Leg", AppendageEffect::Infect, -5);
+    hit(
+        &mut world,
+        patient,
+        "Left Hand",
+        AppendageEffect::Poison,
+        -10,
+    );
+    world
+        .apply_status(
+            patient,
+            StatusEffect::new(StatusKind::Poisoned, 10).with_appendage("Left Hand"),
+        )
+        .unwrap();
+
+    let surgery = Healing::new(medic, patient, "Left Leg", HealingMethod::Surgery).with_amount(3);
+    assert_eq!(world.heal(&surgery).unwrap(), 3);
+    let leg = appendage(&world, patient, "Left Leg");
+    assert_eq!(leg.get_effect_impact(AppendageEffect::Infect), -2);
+    assert!(!leg.is_untreated(AppendageEffect::Infect));
+
+    let spell = Healing::new(medic, patient, "Left Hand", HealingMethod::Magic);
+    assert_eq!(world.heal(&spell).unwrap(), 10);
+    assert!(!world
+        .get_component::<StatusEffects>(patient)
+        .unwrap()
+        .has(StatusKind::Poisoned));
+}
+
+#[test]
+fn test_healers_must_be_able_to_act() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    let medic = spawn(&mut world, "Medic");
+    hit(
+        &mut world,
+        patient,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        -10,
+    );
+    let spell = Healing::new(medic, patient, "Left Arm", HealingMethod::Magic);
+
+    // Rocks and the departed cannot heal.
+    let rock = world.create_prop("Rock", "A plain rock.");
+    let ghost = spawn(&mut world, "Ghost");
+    world.despawn_entity(ghost);
+    for healer in [rock, ghost] {
+        assert!(world
+            .heal(&Healing {
+                healer,
+                ..spell.clone()
+            })
+            .is_err());
+    }
+
+    // Neither can a knocked out medic.
+    hit(&mut world, medic, "Head", AppendageEffect::Abrasion, -45);
+    assert!(world.heal(&spell).is_err());
+    let arm = appendage(&world, patient, "Left Arm");
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Abrasion), -10);
+}
+
+#[test]
+fn test_resting_recovers_over_time() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    hit(
+        &mut world,
+        patient,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        -10,
+    );
+    hit(
+        &mut world,
+        patient,
+        "Right Arm",
+        AppendageEffect::Crush,
+        -40,
+    );
+
+    world.start_resting(patient).unwrap();
+    for _ in 0..5 {
+        world.tick().unwrap();
+    }
+    let arm = appendage(&world, patient, "Left Arm");
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Abrasion), -5);
+    let broken = appendage(&world, patient, "Right Arm");
+    assert_eq!(broken.get_effect_impact(AppendageEffect::Crush), -40);
+
+    world.stop_resting(patient);
+    world.tick().unwrap();
+    let arm = appendage(&world, patient, "Left Arm");
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Abrasion), -5);
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::*;
+use simutron::prelude::*;
+
+#[test]
+fn test_large_and_repeated_damage_saturates() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+
+    hit(
+        &mut world,
+        patient,
+        "Torso",
+        AppendageEffect::Abrasion,
+        -1_000_000,
+    );
+    for _ in 0..100 {
+        hit(
+            &mut world,
+            patient,
+            "Torso",
+            AppendageEffect::Abrasion,
+            Health::MIN,
+        );
+        hit(
+            &mut world,
+            patient,
+            "Torso",
+            AppendageEffect::Burn,
+            Health::MIN,
+        );
+    }
+    let torso = appendage(&world, patient, "Torso");
+    assert_eq!(torso.get_health(), Health::MIN);
+    assert_eq!(torso.get_health_percent(), 0);
+    assert_eq!(torso.get_state(), AppendageHealth::Amputated);
+
+    // Healing saturates the other way and never goes past full health.
+    for _ in 0..3 {
+        hit(
+            &mut world,
+            patient,
+            "Torso",
+            AppendageEffect::Abrasion,
+            Health::MAX,
+        );
+    }
+    let torso = appendage(&world, patient, "Torso");
+    assert_eq!(torso.get_effect_impact(AppendageEffect::Abrasion), 0);
+}
+
+#[test]
+fn test_many_appendages() {
+    let mut centipede = MorphologyBuilder::new("Head");
+    let mut parent = "Head".to_string();
+    for segment in 0..500 {
+        let name = format!("Segment {}", segment);
+        centipede.add_appendage(&parent, name.as_str()).unwrap();
+        centipede
+            .add_appendage(&name, format!("Left Leg {}", segment).as_str())
+            .unwrap();
+        centipede
+            .add_appendage(&name, format!("Right Leg {}", segment).as_str())
+            .unwrap();
+        parent = name;
+    }
+    let mut world = World::new();
+    let centipede = spawn_with(&mut world, "Patient", centipede.build());
+    let body = world.get_component::<Creature>(centipede).unwrap();
+    assert_eq!(body.get_character_health(), 100);
+
+    for segment in 0..500 {
+        let leg = format!("Left Leg {}", segment);
+        hit(&mut world, centipede, &leg, AppendageEffect::Abrasion, -50);
+    }
+    let body = world.get_component::<Creature>(centipede).unwrap();
+    assert_eq!(body.get_character_health(), 83);
+}
+
+#[test]
+fn test_health_scale() {
+    let mut fine = MorphologyBuilder::new("Torso");
+    fine.add_appendage("Torso", "Arm").unwrap();
+    fine.add_appendage("Torso", AppendageSpec::new("Tail").with_max_health(50))
+        .unwrap();
+    fine.set_health_scale(1000);
+    let mut world = World::new();
+    let patient = spawn_with(&mut world, "Patient", fine.build());
+
+    let arm = appendage(&world, patient, "Arm");
+    assert_eq!(arm.get_max_health(), 1000);
+    assert_eq!(appendage(&world, patient, "Tail").get_max_health(), 500);
+
+    // Everything that scales with health keeps the same proportions.
+    hit(&mut world, patient, "Arm", AppendageEffect::Crush, -299);
+    assert_eq!(
+        appendage(&world, patient, "Arm").get_state(),
+        AppendageHealth::Wounded
+    );
+    hit(&mut world, patient, "Arm", AppendageEffect::Crush, -1);
+    assert_eq!(
+        appendage(&world, patient, "Arm").get_state(),
+        AppendageHealth::Disabled
+    );
+
+    hit(&mut world, patient, "Tail", AppendageEffect::Poison, -10);
+    world.tick().unwrap();
+    let torso = appendage(&world, patient, "Torso");
+    assert_eq!(torso.get_effect_impact(AppendageEffect::Poison), -20);
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use common::*;
+use simutron::creatures::morphologies::humanoid::humanoid_corpus;
+use simutron::creatures::morphologies::library::{get_morphology, get_names};
+use simutron::prelude::*;
+use std::collections::HashMap;
+
+#[test]
+fn test_hit_location_tables() {
+    let humanoid = humanoid_corpus();
+    let total: u32 = humanoid
+        .get_hit_locations()
+        .iter()
+        .map(|(_, weight)| weight)
+        .sum();
+    assert_eq!(total, 100);
+    assert_eq!(humanoid.get_hit_location(0).unwrap().get_name(), "Torso");
+    assert_eq!(humanoid.get_hit_location(39).unwrap().get_name(), "Torso");
+    assert_eq!(
+        humanoid.get_hit_location(40).unwrap().get_name(),
+        "Left Arm"
+    );
+    assert_eq!(humanoid.get_hit_location(99).unwrap().get_name(), "Head");
+    assert!(humanoid.get_hit_location(100).is_none());
+
+    // Every bundled body adds up to 100 too.
+    for name in get_names() {
+        let body = get_morphology(name).unwrap().build();
+        let total: u32 = body
+            .get_hit_locations()
+            .iter()
+            .map(|(_, weight)| weight)
+            .sum();
+        assert_eq!(total, 100, "{}", name);
+    }
+
+    // Appendages without weight are never hit at random.
+    let mut turtle = MorphologyBuilder::new(AppendageSpec::new("Shell").with_hit_weight(1));
+    turtle
+        .add_appendage("Shell", AppendageSpec::new("Heart").with_hit_weight(0))
+        .unwrap();
+    let turtle = turtle.build();
+    assert_eq!(turtle.get_hit_locations().len(), 1);
+    assert!(turtle.get_hit_location(1).is_none());
+}
+
+#[test]
+fn test_random_attacks_follow_the_table() {
+    let mut world = World::new();
+    world.seed_dice(21);
+    let alice = spawn(&mut world, "Alice");
+    let bob = spawn(&mut world, "Bob");
+    let attack = Attack::at_random(alice, bob, "1d4").with_modifiers(100, 0);
+
+    let mut landed: HashMap<String, u32> = HashMap::new();
+    for _ in 0..1000 {
+        let entry = world.resolve_attack(&attack).unwrap();
+        assert_eq!(entry.called_shot_penalty, 0);
+        if let Some(action) = &entry.action {
+            assert_eq!(action.target, entry.target);
+        }
+        *landed.entry(entry.target).or_insert(0) += 1;
+    }
+    // Roughly 40% on the torso and 2% on each foot.
+    assert!((330..470).contains(&landed["Torso"]));
+    assert!((5..50).contains(&landed["Left Foot"]));
+    assert_eq!(landed.len(), 10);
+
+    // The same seed lands the same blows.
+    let rolls = |seed: u64| {
+        let mut world = World::new();
+        world.seed_dice(seed);
+        let alice = spawn(&mut world, "Alice");
+        (0..20)
+            .map(|_| world.roll_hit_location(alice).unwrap())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(rolls(4), rolls(4));
+}
+
+#[test]
+fn test_called_shots_take_penalties() {
+    let mut world = World::new();
+    world.seed_dice(2);
+    let alice = spawn(&mut world, "Alice");
+    let bob = spawn(&mut world, "Bob");
+    let penalty = |world: &mut World, target: &str| {
+        world
+            .resolve_attack(&Attack::new(alice, bob, target, "1d4"))
+            .unwrap()
+            .called_shot_penalty
+    };
+    assert_eq!(penalty(&mut world, "Torso"), 0);
+    assert_eq!(penalty(&mut world, "Head"), 4);
+    assert_eq!(penalty(&mut world, "Torso/Right Arm/Right Hand"), 6);
+    assert_eq!(penalty(&mut world, "Left Foot"), 8);
+
+    let entry = world
+        .resolve_attack(&Attack::new(alice, bob, "Right Hand", "1d4"))
+        .unwrap();
+    assert_eq!(entry.target, "Right Hand");
+    assert_eq!(entry.attack_total, entry.attack_roll.total - 6);
+    assert!(world
+        .resolve_attack(&Attack::new(alice, bob, "Tail", "1d4"))
+        .is_err());
+}
+
+#[test]
+fn test_oversized_hit_weights_are_refused() {
+    let mut world = World::new();
+    let mut giant = MorphologyBuilder::new(AppendageSpec::new("Body").with_hit_weight(u32::MAX));
+    giant
+        .add_appendage("Body", AppendageSpec::new("Tail").with_hit_weight(1))
+        .unwrap();
+    let giant = world.create_creature(Creature {
+        name: "Giant".to_string(),
+        corpus: giant.build(),
+    });
+    assert!(world.roll_hit_location(giant).is_err());
+}
@@ -0,0 +1,86 @@
+use simutron::creatures::creature_builder::MAX_MORPHOLOGY_DEPTH;
+use simutron::prelude::*;
+
+fn lizard() -> MorphologyBuilder {
+    let mut lizard = MorphologyBuilder::new("Body");
+    lizard.add_appendage("Body", "Head").unwrap();
+    lizard.add_appendage("Body", "Tail").unwrap();
+    lizard.add_appendage("Tail", "Tail Tip").unwrap();
+    lizard.add_appendage("Body", "Leg").unwrap();
+    lizard.add_appendage("Leg", "Foot").unwrap();
+    lizard
+        .add_capabilities("Head", &[Capability::Vital])
+        .unwrap();
+    lizard
+}
+
+#[test]
+fn test_unknown_parents_and_duplicate_names() {
+    let mut lizard = lizard();
+    assert!(lizard.add_appendage("Wing", "Feather").is_err());
+    assert!(lizard.add_appendage("Leg", "Foot").is_err());
+    assert!(lizard.add_appendage("Tail", "Head").is_err());
+    assert!(lizard
+        .add_capabilities("Wing", &[Capability::Sight])
+        .is_err());
+
+    // Nothing was added by the failed calls.
+    let body = lizard.try_build().unwrap();
+    assert!(body.find("Feather").is_none());
+    assert!(body.find("Tail").unwrap().find("Head").is_none());
+}
+
+#[test]
+fn test_remove_rename_and_move() {
+    let mut lizard = lizard();
+    lizard.remove_appendage("Tail").unwrap();
+    assert!(lizard.remove_appendage("Tail Tip").is_err());
+    assert!(lizard.remove_appendage("Body").is_err());
+
+    lizard.rename_appendage("Leg", "Left Leg").unwrap();
+    assert!(lizard.rename_appendage("Left Leg", "Head").is_err());
+    assert!(lizard.rename_appendage("Tail", "Stump").is_err());
+    lizard.add_appendage("Body", "Right Leg").unwrap();
+
+    // Moving takes everything connected along.
+    lizard.move_appendage("Left Leg", "Right Leg").unwrap();
+    assert!(lizard.move_appendage("Right Leg", "Foot").is_err());
+    assert!(lizard.move_appendage("Right Leg", "Right Leg").is_err());
+    assert!(lizard.move_appendage("Body", "Head").is_err());
+    assert!(lizard.move_appendage("Left Leg", "Wing").is_err());
+    assert!(lizard
+        .move_appendage("Right Leg", "Body/Right Leg/Left Leg/Foot")
+        .is_err());
+
+    let body = lizard.try_build().unwrap();
+    assert!(body.find("Tail").is_none());
+    assert!(body.find("Tail Tip").is_none());
+    assert!(body.find("Leg").is_none());
+    let leg = body.find("Right Leg").unwrap().find("Left Leg").unwrap();
+    assert_eq!(leg.get_spec().name, "Left Leg");
+    assert!(leg.find("Foot").is_some());
+}
+
+#[test]
+fn test_try_build_validates() {
+    let mut lizard = lizard();
+    lizard.remove_appendage("Head").unwrap();
+    assert!(lizard.try_build().is_err());
+    // Building unchecked still works.
+    assert!(lizard.build().find("Head").is_none());
+
+    let mut worm = MorphologyBuilder::new("Segment 0");
+    worm.add_capabilities("Segment 0", &[Capability::Vital])
+        .unwrap();
+    for segment in 1..MAX_MORPHOLOGY_DEPTH {
+        worm.add_appendage(
+            &format!("Segment {}", segment - 1),
+            format!("Segment {}", segment).as_str(),
+        )
+        .unwrap();
+    }
+    assert!(worm.try_build().is_ok());
+    let last = format!("Segment {}", MAX_MORPHOLOGY_DEPTH - 1);
+    worm.add_appendage(&last, "Tail").unwrap();
+    assert!(worm.try_build().is_err());
+}
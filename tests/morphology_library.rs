@@ -0,0 +1,95 @@
+use simutron::creatures::morphologies::library::{get_morphology, get_names};
+use simutron::creatures::morphologies::loader::{load_morphology, parse_morphology};
+use simutron::prelude::*;
+
+#[test]
+fn test_bundled_morphologies() {
+    for name in get_names() {
+        let corpus = get_morphology(name).unwrap().try_build().unwrap();
+        assert!(corpus.count_working(Capability::Vital) > 0, "{}", name);
+        assert!(corpus.count_working(Capability::Locomotion) > 0, "{}", name);
+    }
+    let spider = get_morphology("arachnid").unwrap().build();
+    assert_eq!(spider.count_working(Capability::Locomotion), 8);
+    assert!(spider
+        .find("Cephalothorax")
+        .unwrap()
+        .has_capability(Capability::Vital));
+
+    // Dragons are tougher than most, and hard to burn.
+    let dragon = get_morphology("Dragon").unwrap().build();
+    let torso = dragon.find("Torso").unwrap();
+    assert_eq!(torso.get_max_health(), 300);
+    assert_eq!(torso.get_spec().armor, 8);
+    assert!(get_morphology("Kraken").is_err());
+}
+
+#[test]
+fn test_parse_morphology() {
+    let mut snail = parse_morphology(
+        r#"{
+            "name": "Snail",
+            "health_scale": 200,
+            "cascade": { "bubble_up_percent": 25 },
+            "body": {
+                "name": "Foot",
+                "capabilities": ["Locomotion", "Vital"],
+                "children": [
+                    { "name": "Shell", "armor": 5, "resistances": { "Crush": 50 } },
+                    {
+                        "name": "Eye Stalk",
+                        "max_health": 20,
+                        "thresholds": { "wounded": 50 },
+                        "capabilities": ["Sight"]
+                    }
+                ]
+            }
+        }"#,
+    )
+    .unwrap();
+    // The builder can still be added to after loading.
+    snail.add_appendage("Foot", "Tentacle").unwrap();
+    let snail = snail.build();
+
+    let stalk = snail.find("Eye Stalk").unwrap();
+    assert_eq!(stalk.get_max_health(), 40);
+    assert_eq!(stalk.get_spec().thresholds.wounded, 50);
+    assert_eq!(stalk.get_spec().thresholds.disabled, 16);
+    assert!(stalk.has_capability(Capability::Sight));
+    let shell = snail.find("Shell").unwrap();
+    assert_eq!(shell.get_spec().armor, 5);
+    assert_eq!(shell.get_spec().resistances[&AppendageEffect::Crush], 50);
+    assert_eq!(snail.get_cascade_rules().bubble_up_percent, 25);
+    assert!(snail.get_cascade_rules().sever_children);
+    assert!(snail.find("Tentacle").is_some());
+}
+
+#[test]
+fn test_invalid_morphologies() {
+    let invalid = [
+        "{ not json",
+        r#"{ "name": "Blob" }"#,
+        r#"{ "name": "Blob", "body": { "name": "Core", "capabilities": ["Flight"] } }"#,
+        r#"{ "name": "Blob", "body": { "name": "Core", "resistances": { "Cold": 10 } } }"#,
+        r#"{ "name": "Blob", "body": { "name": "Core", "health": 10 } }"#,
+        r#"{ "name": "Blob", "body": { "name": "Core", "children": [{ "name": "Core" }] } }"#,
+        r#"{ "name": "Blob", "body": { "name": "Core", "children": [{ "name": "Arm" }, { "name": "Arm" }] } }"#,
+    ];
+    for source in invalid {
+        assert!(parse_morphology(source).is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_load_morphology_from_file() {
+    let path = std::env::temp_dir().join("simutron_test_morphology.json");
+    std::fs::write(
+        &path,
+        r#"{ "name": "Worm", "body": { "name": "Body", "capabilities": ["Vital"] } }"#,
+    )
+    .unwrap();
+    let worm = load_morphology(&path).unwrap().build();
+    std::fs::remove_file(&path).unwrap();
+    assert!(worm.has_capability(Capability::Vital));
+    assert!(load_morphology(&path).is_err());
+}
@@ -23,4 +23,410 @@ fn test_movement() {
 
     let forest_map = forest_map.build();
     world.add_map(forest_map.clone());
+    world.create_creature(alice);
+}
+
+/// A 4x1 strip of soil with a cliff in the middle: (0, 0) and (1, 0) are on the ground, (2, 0) and (3, 0) sit on top.
+fn cliff_world(cliff_height: u32, speed: u32) -> (World, Entity, BaseMap<Forest>) {
+    let mut world = World::new();
+    let mut cliff = ForestBuilder::new(4, 1, 5, Tile::new(ForestMaterial::Soil));
+    cliff.add_base_material(
+        2,
+        0,
+        Tile::with_elevation(ForestMaterial::Soil, cliff_height),
+    );
+    cliff.add_base_material(
+        3,
+        0,
+        Tile::with_elevation(ForestMaterial::Soil, cliff_height),
+    );
+    let cliff = cliff.build();
+    world.add_map(cliff.clone());
+
+    let alice = world.create_creature(Creature {
+        name: "Alice".to_string(),
+        corpus: humanoid_corpus(),
+    });
+    world.add_component(
+        alice,
+        CreatureSheet {
+            speed,
+            strength: 5,
+            intelligence: 5,
+            dexterity: 5,
+            constitution: 5,
+            wisdom: 5,
+            charisma: 5,
+        },
+    );
+    (world, alice, cliff)
+}
+
+fn at(map: &BaseMap<Forest>, x: u32, y: u32) -> Position {
+    Position {
+        map: Some(map.id),
+        x,
+        y,
+        z: 0,
+    }
+}
+
+#[test]
+fn test_climbing_costs_extra_movement() {
+    // Climbing 10 costs double on top of the 5 to cross the tile.
+    let (mut world, alice, cliff) = cliff_world(10, 25);
+    world.teleport_creature(alice, at(&cliff, 1, 0)).unwrap();
+    world.move_creature(alice, vec![at(&cliff, 2, 0)]).unwrap();
+    let position = world.get_component::<Position>(alice).unwrap();
+    assert_eq!((position.x, position.z), (2, 10));
+
+    let (mut world, alice, cliff) = cliff_world(10, 20);
+    world.teleport_creature(alice, at(&cliff, 1, 0)).unwrap();
+    world.move_creature(alice, vec![at(&cliff, 2, 0)]).unwrap();
+    let position = world.get_component::<Position>(alice).unwrap();
+    assert_eq!(
+        (position.x, position.z),
+        (1, 0),
+        "Alice is too slow to climb"
+    );
+}
+
+#[test]
+fn test_teleporting_lands_on_the_ground() {
+    let (mut world, alice, cliff) = cliff_world(10, 5);
+    world.teleport_creature(alice, at(&cliff, 2, 0)).unwrap();
+    assert_eq!(world.get_component::<Position>(alice).unwrap().z, 10);
+
+    // Bob is found on the clifftop, however he got there.
+    let bob = world.create_creature(Creature {
+        name: "Bob".to_string(),
+        corpus: humanoid_corpus(),
+    });
+    world.teleport_creature(bob, at(&cliff, 3, 0)).unwrap();
+    let report = world.move_creature(alice, vec![at(&cliff, 3, 0)]).unwrap();
+    assert_eq!(report.stop_reason, StopReason::Occupied);
+
+    // Alice walks along the top without climbing anything.
+    world.teleport_creature(bob, at(&cliff, 0, 0)).unwrap();
+    let report = world.move_creature(alice, vec![at(&cliff, 3, 0)]).unwrap();
+    assert!(report.is_complete());
+    assert_eq!(report.spent, 5);
+}
+
+#[test]
+fn test_falling_hurts() {
+    let (mut world, alice, cliff) = cliff_world(5, 30);
+    let mut start = at(&cliff, 2, 0);
+    start.z = 5;
+    world.teleport_creature(alice, start).unwrap();
+    world.move_creature(alice, vec![at(&cliff, 1, 0)]).unwrap();
+    let health = world
+        .get_creature_by_name("Alice")
+        .unwrap()
+        .1
+        .get_character_health();
+    assert_eq!(health, 100, "A drop of one tile is safe");
+
+    let (mut world, alice, cliff) = cliff_world(20, 30);
+    let mut start = at(&cliff, 2, 0);
+    start.z = 20;
+    world.teleport_creature(alice, start).unwrap();
+    world.move_creature(alice, vec![at(&cliff, 1, 0)]).unwrap();
+    let position = world.get_component::<Position>(alice).unwrap();
+    assert_eq!((position.x, position.z), (1, 0));
+    let health = world
+        .get_creature_by_name("Alice")
+        .unwrap()
+        .1
+        .get_character_health();
+    assert!(health < 100, "Falling from a cliff should hurt");
+}
+
+#[test]
+fn test_fatal_fall_leaves_a_corpse() {
+    let (mut world, alice, _) = cliff_world(0, 30);
+    world.insert_resource(DeathRules {
+        leave_corpses: true,
+    });
+    let mut stairs = ForestBuilder::new(3, 1, 5, Tile::new(ForestMaterial::Soil));
+    stairs.add_base_material(1, 0, Tile::with_elevation(ForestMaterial::Soil, 500));
+    stairs.add_base_material(2, 0, Tile::with_elevation(ForestMaterial::Soil, 1000));
+    let stairs = stairs.build();
+    world.add_map(stairs.clone());
+    let mut start = at(&stairs, 2, 0);
+    start.z = 1000;
+    world.teleport_creature(alice, start).unwrap();
+
+    // The first landing is fatal. The second drop does not hurt what is left.
+    let report = world
+        .move_creature(alice, vec![at(&stairs, 1, 0), at(&stairs, 0, 0)])
+        .unwrap();
+    assert!(report.is_complete());
+    assert!(world.get_component::<Creature>(alice).is_none());
+    let corpse = world.get_entities_with::<Corpse>()[0];
+    assert_eq!(world.get_component::<Position>(corpse).unwrap().x, 0);
+}
+
+#[test]
+fn test_line_of_sight_over_elevation() {
+    let mut world = World::new();
+    let mut ridge = ForestBuilder::new(5, 1, 5, Tile::new(ForestMaterial::Grass));
+    ridge.add_base_material(2, 0, Tile::with_elevation(ForestMaterial::Grass, 10));
+    let ridge = ridge.build();
+    world.add_map(ridge.clone());
+
+    let low_left = at(&ridge, 0, 0);
+    let low_right = at(&ridge, 4, 0);
+    assert!(!world.has_line_of_sight(low_left, low_right).unwrap());
+
+    let mut high_left = low_left;
+    high_left.z = 20;
+    let mut high_right = low_right;
+    high_right.z = 20;
+    assert!(world.has_line_of_sight(high_left, high_right).unwrap());
+}
+
+#[test]
+fn test_movement_rules() {
+    // Two diagonal steps cost 5 + 10 under the default rules, so a speed of 10 only affords the first.
+    let (mut world, alice, _) = cliff_world(0, 10);
+    let mut field = ForestBuilder::new(3, 3, 5, Tile::new(ForestMaterial::Soil));
+    field.add_base_material(1, 1, Tile::new(ForestMaterial::Stream));
+    let field = field.build();
+    world.add_map(field.clone());
+    let diagonals = vec![at(&field, 1, 1), at(&field, 2, 2)];
+
+    world.teleport_creature(alice, at(&field, 0, 0)).unwrap();
+    world.move_creature(alice, diagonals.clone()).unwrap();
+    let position = world.get_component::<Position>(alice).unwrap();
+    assert_eq!((position.x, position.y), (1, 1), "Stuck in the stream");
+
+    // Chebyshev diagonals cost a single tile and terrain no longer matters.
+    world.insert_resource(MovementRules {
+        diagonals: DiagonalPolicy::Chebyshev,
+        terrain: TerrainCostPolicy::Ignored,
+        allow_partial_moves: true,
+    });
+    world.teleport_creature(alice, at(&field, 0, 0)).unwrap();
+    world.move_creature(alice, diagonals.clone()).unwrap();
+    let position = world.get_component::<Position>(alice).unwrap();
+    assert_eq!((position.x, position.y), (2, 2));
+    assert_eq!(
+        world
+            .get_distance(at(&field, 0, 0), at(&field, 2, 2))
+            .unwrap(),
+        10
+    );
+
+    // Manhattan diagonals cost two tiles, and partial moves are refused outright.
+    world.insert_resource(MovementRules {
+        diagonals: DiagonalPolicy::Manhattan,
+        terrain: TerrainCostPolicy::Ignored,
+        allow_partial_moves: false,
+    });
+    world.teleport_creature(alice, at(&field, 0, 0)).unwrap();
+    assert!(world.move_creature(alice, diagonals).is_err());
+    let position = world.get_component::<Position>(alice).unwrap();
+    assert_eq!((position.x, position.y), (0, 0));
+    assert_eq!(
+        world
+            .get_distance(at(&field, 0, 0), at(&field, 2, 2))
+            .unwrap(),
+        20
+    );
+}
+
+#[test]
+fn test_alternating_diagonals_count_only_diagonals() {
+    // The alternation used to follow the step's index in the path, so a diagonal in second place
+    // cost two tiles (5 + 10 = 15). It now follows the number of diagonals taken, so the first
+    // diagonal is always the cheap one (5 + 5 = 10).
+    let (mut world, alice, _) = cliff_world(0, 10);
+    let field = ForestBuilder::new(3, 3, 5, Tile::new(ForestMaterial::Soil)).build();
+    world.add_map(field.clone());
+    world.teleport_creature(alice, at(&field, 0, 0)).unwrap();
+
+    let report = world
+        .move_creature(alice, vec![at(&field, 1, 0), at(&field, 2, 1)])
+        .unwrap();
+    assert!(report.is_complete());
+    assert_eq!(
+        report
+            .steps
+            .iter()
+            .map(|step| step.cost)
+            .collect::<Vec<_>>(),
+        vec![5, 5]
+    );
+
+    // The second diagonal is still the expensive one, however many straight steps come between.
+    world.teleport_creature(alice, at(&field, 0, 0)).unwrap();
+    let report = world
+        .move_creature(
+            alice,
+            vec![at(&field, 1, 1), at(&field, 1, 2), at(&field, 2, 1)],
+        )
+        .unwrap();
+    assert_eq!(
+        report
+            .steps
+            .iter()
+            .map(|step| step.cost)
+            .collect::<Vec<_>>(),
+        vec![5, 5]
+    );
+    assert_eq!(report.stop_reason, StopReason::OutOfMovement);
+}
+
+#[test]
+fn test_movement_modes() {
+    let (mut world, alice, _) = cliff_world(0, 10);
+    let mut creek = ForestBuilder::new(4, 1, 5, Tile::new(ForestMaterial::Soil));
+    creek.add_base_material(1, 0, Tile::new(ForestMaterial::Stream));
+    creek.add_base_material(3, 0, Tile::new(ForestMaterial::FallenTree));
+    let creek = creek.build();
+    world.add_map(creek.clone());
+    let across = vec![at(&creek, 1, 0), at(&creek, 2, 0)];
+
+    // Wading out of the stream is hard work on foot.
+    world.teleport_creature(alice, at(&creek, 0, 0)).unwrap();
+    world.move_creature(alice, across.clone()).unwrap();
+    let position = world.get_component::<Position>(alice).unwrap();
+    assert_eq!(position.x, 1, "Walkers get stuck in the stream");
+
+    // Swimmers cross freely.
+    world.add_component(
+        alice,
+        MovementModes::new().with_speed(MovementMode::Swim, 10),
+    );
+    world.teleport_creature(alice, at(&creek, 0, 0)).unwrap();
+    world.move_creature(alice, across).unwrap();
+    let position = world.get_component::<Position>(alice).unwrap();
+    assert_eq!(position.x, 2, "Swimmers cross the stream");
+
+    // Nobody walks through a fallen tree, but fliers pass right over.
+    world.move_creature(alice, vec![at(&creek, 3, 0)]).unwrap();
+    let position = world.get_component::<Position>(alice).unwrap();
+    assert_eq!(position.x, 2, "The fallen tree blocks walkers");
+
+    world.add_component(
+        alice,
+        MovementModes::new().with_speed(MovementMode::Fly, 10),
+    );
+    world.move_creature(alice, vec![at(&creek, 3, 0)]).unwrap();
+    let position = world.get_component::<Position>(alice).unwrap();
+    assert_eq!(position.x, 3, "Fliers pass over the fallen tree");
+}
+
+#[test]
+fn test_modes_only_step_out_onto_open_ground() {
+    let (mut world, alice, _) = cliff_world(0, 5);
+    let mut creek = ForestBuilder::new(3, 1, 5, Tile::new(ForestMaterial::Soil));
+    creek.add_base_material(1, 0, Tile::new(ForestMaterial::Stream));
+    let creek = creek.build();
+    world.add_map(creek.clone());
+
+    // Burrowers cannot dig their way into water.
+    world.add_component(
+        alice,
+        MovementModes::new()
+            .with_speed(MovementMode::Walk, 0)
+            .with_speed(MovementMode::Burrow, 10),
+    );
+    world.teleport_creature(alice, at(&creek, 0, 0)).unwrap();
+    let report = world.move_creature(alice, vec![at(&creek, 1, 0)]).unwrap();
+    assert_eq!(report.stop_reason, StopReason::Blocked);
+
+    // A swimmer climbing out onto the bank walks that step, at walking speed.
+    world.add_component(
+        alice,
+        MovementModes::new().with_speed(MovementMode::Swim, 20),
+    );
+    world.teleport_creature(alice, at(&creek, 1, 0)).unwrap();
+    let report = world.move_creature(alice, vec![at(&creek, 2, 0)]).unwrap();
+    assert!(report.is_complete());
+    assert_eq!(report.steps[0].mode, MovementMode::Walk);
+    assert_eq!(report.steps[0].cost, 20);
+}
+
+#[test]
+fn test_movement_report() {
+    let (mut world, alice, strip) = cliff_world(0, 12);
+    world.teleport_creature(alice, at(&strip, 0, 0)).unwrap();
+
+    // Two steps cost 10, the third is out of reach.
+    let report = world
+        .move_creature(
+            alice,
+            vec![at(&strip, 1, 0), at(&strip, 2, 0), at(&strip, 3, 0)],
+        )
+        .unwrap();
+    assert_eq!(report.stop_reason, StopReason::OutOfMovement);
+    assert!(!report.is_complete());
+    assert_eq!(report.steps.len(), 2);
+    assert!(report.steps.iter().all(|step| step.cost == 5));
+    assert_eq!((report.spent, report.remaining), (10, 2));
+    assert_eq!(report.get_final_position().map(|p| p.x), Some(2));
+
+    // Walking off the end of the map stops at the edge.
+    let report = world
+        .move_creature(alice, vec![at(&strip, 3, 0), at(&strip, 4, 0)])
+        .unwrap();
+    assert_eq!(report.stop_reason, StopReason::MapEdge);
+    assert_eq!(report.get_final_position().map(|p| p.x), Some(3));
+
+    // Bob is in the way.
+    let bob = world.create_creature(Creature {
+        name: "Bob".to_string(),
+        corpus: humanoid_corpus(),
+    });
+    world.teleport_creature(bob, at(&strip, 1, 0)).unwrap();
+    let report = world
+        .move_creature(alice, vec![at(&strip, 2, 0), at(&strip, 1, 0)])
+        .unwrap();
+    assert_eq!(report.stop_reason, StopReason::Occupied);
+    assert_eq!(report.get_final_position().map(|p| p.x), Some(2));
+}
+
+#[test]
+fn test_movement_budget() {
+    let (mut world, alice, strip) = cliff_world(0, 10);
+    world.teleport_creature(alice, at(&strip, 0, 0)).unwrap();
+
+    // Outside an encounter there are no turns, so every move gets the full speed.
+    world.move_creature(alice, vec![at(&strip, 1, 0)]).unwrap();
+    assert_eq!(world.get_remaining_movement(alice).unwrap(), 10);
+    world.teleport_creature(alice, at(&strip, 0, 0)).unwrap();
+
+    // In an encounter, moving twice in one turn spends from the same budget.
+    world.start_encounter(&[alice]).unwrap();
+    world.move_creature(alice, vec![at(&strip, 1, 0)]).unwrap();
+    let report = world
+        .move_creature(alice, vec![at(&strip, 2, 0), at(&strip, 3, 0)])
+        .unwrap();
+    assert_eq!(report.stop_reason, StopReason::OutOfMovement);
+    assert_eq!(report.remaining, 0);
+    assert_eq!(world.get_component::<Position>(alice).unwrap().x, 2);
+
+    // Dashing grants another full speed this turn.
+    let budget = world.dash(alice).unwrap();
+    assert_eq!((budget.spent, budget.dashes), (10, 1));
+    assert_eq!(world.get_remaining_movement(alice).unwrap(), 10);
+    world.move_creature(alice, vec![at(&strip, 3, 0)]).unwrap();
+    assert_eq!(world.get_remaining_movement(alice).unwrap(), 5);
+
+    // What is left follows the creature's current speed.
+    world
+        .get_component_mut::<CreatureSheet>(alice)
+        .unwrap()
+        .speed = 20;
+    assert_eq!(world.get_remaining_movement(alice).unwrap(), 25);
+
+    // A new turn clears the budget and forgets the dash.
+    world.end_turn().unwrap();
+    assert_eq!(
+        world.get_component::<MovementBudget>(alice),
+        Some(&MovementBudget::new())
+    );
+    assert_eq!(world.get_remaining_movement(alice).unwrap(), 20);
 }
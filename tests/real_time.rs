@@ -0,0 +1,143 @@
+use simutron::creatures::morphologies::humanoid::humanoid_corpus;
+use simutron::prelude::*;
+use std::time::Duration;
+
+fn at(map: &BaseMap<Forest>, x: u32) -> Position {
+    Position {
+        map: Some(map.id),
+        x,
+        y: 0,
+        z: 0,
+    }
+}
+
+/// A 5x1 strip of soil with gravel at the start. At speed 30 and the default six second turn,
+/// Alice covers a 5 unit tile every second, and leaving the gravel takes two.
+fn trail_world() -> (World, Entity, BaseMap<Forest>) {
+    let mut world = World::new();
+    let mut trail = ForestBuilder::new(5, 1, 5, Tile::new(ForestMaterial::Soil));
+    trail.add_base_material(0, 0, Tile::new(ForestMaterial::Gravel));
+    let trail = trail.build();
+    world.add_map(trail.clone());
+
+    let alice = world.create_creature(Creature {
+        name: "Alice".to_string(),
+        corpus: humanoid_corpus(),
+    });
+    world.add_component(
+        alice,
+        CreatureSheet {
+            speed: 30,
+            strength: 5,
+            intelligence: 5,
+            dexterity: 5,
+            constitution: 5,
+            wisdom: 5,
+            charisma: 5,
+        },
+    );
+    world.teleport_creature(alice, at(&trail, 0)).unwrap();
+    (world, alice, trail)
+}
+
+fn x_of(world: &World, creature: Entity) -> u32 {
+    world.get_component::<Position>(creature).unwrap().x
+}
+
+#[test]
+fn test_movement_takes_time() {
+    let (mut world, alice, trail) = trail_world();
+    world
+        .order_movement(alice, (1..5).map(|x| at(&trail, x)).collect())
+        .unwrap();
+
+    // Leaving the gravel takes two seconds.
+    let report = world.advance(Duration::from_millis(1500)).unwrap();
+    assert!(report.movements.is_empty());
+    assert_eq!(x_of(&world, alice), 0);
+    world.advance(Duration::from_millis(500)).unwrap();
+    assert_eq!(x_of(&world, alice), 1);
+
+    // Soil takes a second a tile. Leftover time carries over to the next call.
+    world.advance(Duration::from_millis(1500)).unwrap();
+    assert_eq!(x_of(&world, alice), 2);
+    world.advance(Duration::from_millis(500)).unwrap();
+    assert_eq!(x_of(&world, alice), 3);
+
+    let report = world.advance(Duration::from_secs(10)).unwrap();
+    assert_eq!(x_of(&world, alice), 4);
+    assert_eq!(report.movements.len(), 1);
+    assert!(report.movements[0].is_complete());
+    assert!(world.get_component::<MovementOrder>(alice).is_none());
+    assert_eq!(world.get_clock().elapsed, Duration::from_secs(14));
+}
+
+#[test]
+fn test_clock_turn_length_scales_travel_time() {
+    let (mut world, alice, trail) = trail_world();
+    world.insert_resource(GameClock::new(Duration::from_secs(3)));
+    world
+        .order_movement(alice, vec![at(&trail, 1), at(&trail, 2)])
+        .unwrap();
+
+    // Half the turn length doubles the pace: one second gets off the gravel and onto the next tile.
+    world.advance(Duration::from_millis(1500)).unwrap();
+    assert_eq!(x_of(&world, alice), 2);
+
+    world.order_movement(alice, vec![at(&trail, 3)]).unwrap();
+    world.cancel_movement(alice);
+    world.advance(Duration::from_secs(5)).unwrap();
+    assert_eq!(x_of(&world, alice), 2);
+}
+
+#[test]
+fn test_bad_movement_order_leaves_world_untouched() {
+    let (mut world, alice, trail) = trail_world();
+    assert!(world.order_movement(alice, vec![at(&trail, 2)]).is_err());
+
+    // Teleporting away leaves the order's path out of reach. Advancing fails before the clock moves.
+    world
+        .order_movement(alice, vec![at(&trail, 1), at(&trail, 2)])
+        .unwrap();
+    world.teleport_creature(alice, at(&trail, 4)).unwrap();
+    assert!(world.advance(Duration::from_secs(12)).is_err());
+    assert_eq!(world.get_clock(), GameClock::default());
+    assert_eq!(x_of(&world, alice), 4);
+
+    world.cancel_movement(alice);
+    world.advance(Duration::from_secs(12)).unwrap();
+    assert_eq!(world.get_clock().ticks, 2);
+}
+
+#[test]
+fn test_actions_and_cooldowns() {
+    let (mut world, alice, _) = trail_world();
+    let reload = Duration::from_secs(2);
+    let cooldown = Duration::from_secs(3);
+    world
+        .start_action(alice, "Reload", reload, cooldown)
+        .unwrap();
+    assert!(world
+        .start_action(alice, "Shove", reload, cooldown)
+        .is_err());
+
+    let report = world.advance(Duration::from_secs(1)).unwrap();
+    assert!(report.completed_actions.is_empty());
+    let report = world.advance(Duration::from_secs(2)).unwrap();
+    assert_eq!(
+        report.completed_actions,
+        vec![(alice, "Reload".to_string())]
+    );
+
+    // The extra second past completion already counts towards the cooldown.
+    let timers = world.get_component::<ActionTimers>(alice).unwrap();
+    assert!(!timers.is_busy());
+    assert!(!timers.is_ready("Reload"));
+    assert!(world
+        .start_action(alice, "Reload", reload, cooldown)
+        .is_err());
+    world.advance(Duration::from_secs(2)).unwrap();
+    world
+        .start_action(alice, "Reload", reload, cooldown)
+        .unwrap();
+}
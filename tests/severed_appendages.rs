@@ -0,0 +1,141 @@
+use simutron::creatures::morphologies::humanoid::humanoid_corpus;
+use simutron::creatures::morphologies::roper::roper_corpus;
+use simutron::prelude::*;
+
+fn spawn(world: &mut World, name: &str, corpus: Appendage) -> Entity {
+    let creature = world.create_creature(Creature {
+        name: name.to_string(),
+        corpus,
+    });
+    let mut clearing = ForestBuilder::new(3, 3, 5, Tile::new(ForestMaterial::Grass));
+    clearing.add_name("Clearing");
+    let clearing = clearing.build();
+    world.add_map(clearing.clone());
+    world
+        .teleport_creature(
+            creature,
+            Position {
+                map: Some(clearing.id),
+                x: 1,
+                y: 2,
+                z: 0,
+            },
+        )
+        .unwrap();
+    creature
+}
+
+fn sever_left_arm(world: &mut World, creature: Entity) -> Entity {
+    world
+        .apply_creature_action(&CreatureActions {
+            from: creature,
+            to: creature,
+            target: "Left Arm".to_string(),
+            effect: AppendageEffect::Abrasion,
+            impact: -90,
+        })
+        .unwrap();
+    world.get_entities_with::<SeveredAppendage>()[0]
+}
+
+#[test]
+fn test_severed_appendage_becomes_prop() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice", humanoid_corpus());
+    let arm = sever_left_arm(&mut world, alice);
+
+    let body = world.get_component::<Creature>(alice).unwrap();
+    assert!(body.corpus.find("Left Arm").is_none());
+    assert!(body.corpus.find("Left Hand").is_none());
+
+    let prop = world.get_component::<Prop>(arm).unwrap();
+    assert_eq!(prop.name, "Alice's Left Arm");
+    assert_eq!(world.get_component::<PropHealth>(arm).unwrap().health, 10);
+    let position = *world.get_component::<Position>(alice).unwrap();
+    assert_eq!(world.get_component::<Position>(arm), Some(&position));
+    assert!(world.get_entities_at(&position).contains(&arm));
+
+    let severed = world.get_component::<SeveredAppendage>(arm).unwrap();
+    assert_eq!(severed.owner, alice);
+    assert_eq!(severed.attached_to, "Torso");
+    assert!(severed.appendage.find("Left Hand").is_some());
+}
+
+#[test]
+fn test_reattach_severed_appendage() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice", humanoid_corpus());
+    let bob = spawn(&mut world, "Bob", humanoid_corpus());
+    let arm_id = world
+        .get_component::<Creature>(alice)
+        .unwrap()
+        .find("Left Arm")
+        .unwrap()
+        .get_id();
+    let arm = sever_left_arm(&mut world, alice);
+    world.add_component(bob, Inventory::new());
+    world.add_to_inventory(bob, arm.0).unwrap();
+
+    // Bob has both arms, so the spare one cannot go on him.
+    assert!(world.reattach_appendage(bob, arm).is_err());
+    world.reattach_appendage(alice, arm).unwrap();
+
+    let body = world.get_component::<Creature>(alice).unwrap();
+    let arm_state = body.corpus.find("Left Arm").unwrap().get_state();
+    let hand_state = body.corpus.find("Left Hand").unwrap().get_state();
+    assert_eq!(arm_state, AppendageHealth::Wounded);
+    assert_eq!(hand_state, AppendageHealth::Full);
+    assert_eq!(body.corpus.find("Left Arm").unwrap().get_id(), arm_id);
+    assert!(world.get_component::<Prop>(arm).is_none());
+    assert!(world.get_entities_with::<SeveredAppendage>().is_empty());
+    assert!(world
+        .get_component::<Inventory>(bob)
+        .unwrap()
+        .items
+        .is_empty());
+}
+
+#[test]
+fn test_reattach_needs_somewhere_to_attach() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice", humanoid_corpus());
+    let roper = spawn(&mut world, "Roper", roper_corpus());
+    let arm = sever_left_arm(&mut world, alice);
+
+    assert!(world.reattach_appendage(roper, arm).is_err());
+    assert!(world.reattach_appendage(alice, roper).is_err());
+    assert!(world.get_component::<SeveredAppendage>(arm).is_some());
+}
+
+#[test]
+fn test_parts_from_other_bodies_get_fresh_ids() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice", humanoid_corpus());
+    let mut lizard = MorphologyBuilder::new("Torso");
+    lizard.add_appendage("Torso", "Tail").unwrap();
+    lizard.add_appendage("Tail", "Tail Tip").unwrap();
+    let lizard = spawn(&mut world, "Lizard", lizard.build());
+    let arm = sever_left_arm(&mut world, alice);
+    world.reattach_appendage(lizard, arm).unwrap();
+
+    let body = &world.get_component::<Creature>(lizard).unwrap().corpus;
+    let mut ids: Vec<_> = body.iter().map(|part| part.get_id()).collect();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 5);
+
+    // A blow to the new arm stays on the arm.
+    world
+        .apply_creature_action(&CreatureActions {
+            from: lizard,
+            to: lizard,
+            target: "Left Hand".to_string(),
+            effect: AppendageEffect::Abrasion,
+            impact: -10,
+        })
+        .unwrap();
+    let body = &world.get_component::<Creature>(lizard).unwrap().corpus;
+    assert_eq!(body.find("Tail").unwrap().get_health_percent(), 100);
+    assert_eq!(body.find("Tail Tip").unwrap().get_health_percent(), 100);
+    assert_eq!(body.find("Left Hand").unwrap().get_health_percent(), 90);
+}
@@ -27,15 +27,15 @@ fn test_simutron_integration() {
     let mut humanoid = MorphologyBuilder::new("Torso");
     // Then you add appendages to it.
     // Each appendage has its own state and health value. More on that later.
-    humanoid.add_appendage("Torso", "Left Arm");
-    humanoid.add_appendage("Torso", "Right Arm");
-    humanoid.add_appendage("Left Arm", "Left Hand");
-    humanoid.add_appendage("Right Arm", "Right Hand");
-    humanoid.add_appendage("Torso", "Left Leg");
-    humanoid.add_appendage("Torso", "Right Leg");
-    humanoid.add_appendage("Left Leg", "Left Foot");
-    humanoid.add_appendage("Right Leg", "Right Foot");
-    humanoid.add_appendage("Torso", "Head");
+    humanoid.add_appendage("Torso", "Left Arm").unwrap();
+    humanoid.add_appendage("Torso", "Right Arm").unwrap();
+    humanoid.add_appendage("Left Arm", "Left Hand").unwrap();
+    humanoid.add_appendage("Right Arm", "Right Hand").unwrap();
+    humanoid.add_appendage("Torso", "Left Leg").unwrap();
+    humanoid.add_appendage("Torso", "Right Leg").unwrap();
+    humanoid.add_appendage("Left Leg", "Left Foot").unwrap();
+    humanoid.add_appendage("Right Leg", "Right Foot").unwrap();
+    humanoid.add_appendage("Torso", "Head").unwrap();
 
     let humanoid_structure = humanoid.build();
     println!("Here's our humanoid morphology:");
@@ -207,14 +207,18 @@ fn test_simutron_integration() {
         map: Some(forest_map.id),
         x: 0,
         y: 4,
+        z: 0,
     };
     world.teleport_creature(alice, alice_spawn_point).unwrap();
 
     let alice_pos = world.get_component::<Position>(alice).unwrap();
     println!("Alice's spawn position is: {:#?}", alice_pos);
 
+    // Alice spawns on the gravel strip at x = 0, and gravel costs double to cross.
+    // The first leg costs 10 + 10 + 5 = 25, so the original speed of 15 never got her to (2, 2)
+    // and this test failed on its first move before elevation was added.
     let alice_creature_sheet = CreatureSheet {
-        speed: 15,
+        speed: 25,
         strength: 5,
         intelligence: 5,
         dexterity: 5,
@@ -228,16 +232,19 @@ fn test_simutron_integration() {
         map: Some(forest_map.id),
         x: 0,
         y: 3,
+        z: 0,
     };
     let move_right = Position {
         map: Some(forest_map.id),
         x: 1,
         y: 3,
+        z: 0,
     };
     let move_diagonal = Position {
         map: Some(forest_map.id),
         x: 2,
         y: 2,
+        z: 0,
     };
     let movement_positions = vec![move_up, move_right, move_diagonal];
 
@@ -256,11 +263,13 @@ fn test_simutron_integration() {
         map: Some(forest_map.id),
         x: 3,
         y: 1,
+        z: 0,
     };
     let diag_2 = Position {
         map: Some(forest_map.id),
         x: 4,
         y: 0,
+        z: 0,
     };
     let movement_positions = vec![diag_1, diag_2];
     world.move_creature(alice, movement_positions).unwrap();
@@ -278,18 +287,29 @@ fn test_simutron_integration() {
         map: Some(forest_map.id),
         x: 3,
         y: 1,
+        z: 0,
     };
     let diag_2 = Position {
         map: Some(forest_map.id),
         x: 2,
         y: 2,
+        z: 0,
     };
     let diag_3 = Position {
         map: Some(forest_map.id),
         x: 1,
         y: 3,
+        z: 0,
     };
-    let movement_positions = vec![diag_1, diag_2, diag_3];
+    // With a speed of 25 the three diagonals back (5 + 10 + 5) are all affordable,
+    // so a fourth step onto the gravel is requested to keep checking that she stops partway.
+    let diag_4 = Position {
+        map: Some(forest_map.id),
+        x: 0,
+        y: 4,
+        z: 0,
+    };
+    let movement_positions = vec![diag_1, diag_2, diag_3, diag_4];
     world.move_creature(alice, movement_positions).unwrap();
     let alice_pos = world.get_component::<Position>(alice).unwrap();
     println!("Alice's final position is: {:#?}", alice_pos);
@@ -299,7 +319,7 @@ fn test_simutron_integration() {
 
     // Verify test assertions
     assert!(
-        alice_pos.x == 2 && alice_pos.y == 2,
-        "Alice should end at position (2, 2)"
+        alice_pos.x == 1 && alice_pos.y == 3,
+        "Alice should end at position (1, 3)"
     );
 }
@@ -0,0 +1,168 @@
+mod common;
+
+use common::*;
+use simutron::creatures::morphologies::library::get_morphology;
+use simutron::prelude::*;
+
+fn statuses(world: &World, creature: Entity) -> Vec<StatusEffect> {
+    world
+        .get_component::<StatusEffects>(creature)
+        .map(|statuses| statuses.get_effects().to_vec())
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_bleeding_ticks_until_it_wears_off() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    let bob = spawn(&mut world, "Bob");
+    world
+        .apply_status(
+            alice,
+            StatusEffect::new(StatusKind::Bleeding, 3)
+                .with_appendage("Left Arm")
+                .with_source(bob),
+        )
+        .unwrap();
+
+    for _ in 0..4 {
+        world.tick().unwrap();
+    }
+    let arm = appendage(&world, alice, "Left Arm");
+    assert_eq!(arm.get_effect_impact(AppendageEffect::Abrasion), -6);
+    assert_eq!(arm.get_timeline_from(bob).count(), 3);
+    assert!(statuses(&world, alice).is_empty());
+
+    // Burning with no appendage named burns the root of the body.
+    world
+        .apply_status(alice, StatusEffect::new(StatusKind::Burning, 1))
+        .unwrap();
+    world.tick().unwrap();
+    let torso = appendage(&world, alice, "Torso");
+    assert_eq!(torso.get_effect_impact(AppendageEffect::Burn), -3);
+    assert!(world
+        .apply_status(
+            alice,
+            StatusEffect::new(StatusKind::Bleeding, 1).with_appendage("Tail")
+        )
+        .is_err());
+}
+
+#[test]
+fn test_armor_does_not_stop_bleeding() {
+    let mut world = World::new();
+    let dragon = spawn_with(
+        &mut world,
+        "Dragon",
+        get_morphology("Dragon").unwrap().build(),
+    );
+    // A cut this shallow glances off the scales...
+    hit(&mut world, dragon, "Torso", AppendageEffect::Abrasion, -5);
+    let torso = appendage(&world, dragon, "Torso");
+    assert_eq!(torso.get_effect_impact(AppendageEffect::Abrasion), 0);
+
+    // ...but bleeding starts under them.
+    world
+        .apply_status(
+            dragon,
+            StatusEffect::new(StatusKind::Bleeding, 2).with_potency(5),
+        )
+        .unwrap();
+    world.tick().unwrap();
+    world.tick().unwrap();
+    let torso = appendage(&world, dragon, "Torso");
+    assert_eq!(torso.get_effect_impact(AppendageEffect::Abrasion), -10);
+}
+
+#[test]
+fn test_stacking_rules() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    let bleed = StatusEffect::new(StatusKind::Bleeding, 2).with_appendage("Left Leg");
+    world.apply_status(alice, bleed.clone()).unwrap();
+    world
+        .apply_status(alice, bleed.clone().with_potency(3))
+        .unwrap();
+    world
+        .apply_status(alice, StatusEffect::new(StatusKind::Poisoned, 2))
+        .unwrap();
+    world
+        .apply_status(alice, StatusEffect::new(StatusKind::Poisoned, 3))
+        .unwrap();
+    world
+        .apply_status(alice, StatusEffect::new(StatusKind::Burning, 4))
+        .unwrap();
+    world
+        .apply_status(
+            alice,
+            StatusEffect::new(StatusKind::Burning, 2).with_potency(5),
+        )
+        .unwrap();
+    world
+        .apply_status(alice, bleed.with_stacking(Stacking::Independent))
+        .unwrap();
+
+    let effects = statuses(&world, alice);
+    assert_eq!(effects.len(), 4);
+    assert_eq!((effects[0].potency, effects[0].remaining), (5, 2));
+    assert_eq!((effects[1].potency, effects[1].remaining), (1, 5));
+    assert_eq!((effects[2].potency, effects[2].remaining), (5, 4));
+    assert_eq!((effects[3].potency, effects[3].remaining), (2, 2));
+}
+
+#[test]
+fn test_treatment_removes_statuses() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    for arm in ["Left Arm", "Right Arm"] {
+        world
+            .apply_status(
+                alice,
+                StatusEffect::new(StatusKind::Bleeding, 10).with_appendage(arm),
+            )
+            .unwrap();
+    }
+    world
+        .apply_status(alice, StatusEffect::new(StatusKind::Stunned, 10))
+        .unwrap();
+
+    // Bandaging the left arm stops only its bleeding.
+    world
+        .apply_creature_action(&CreatureActions {
+            from: alice,
+            to: alice,
+            target: "Left Arm".to_string(),
+            effect: AppendageEffect::Abrasion,
+            impact: 1,
+        })
+        .unwrap();
+    let effects = statuses(&world, alice);
+    assert_eq!(effects.len(), 2);
+    assert_eq!(effects[0].appendage.as_deref(), Some("Right Arm"));
+
+    assert!(world.is_stunned(alice));
+    let removed = world.remove_status(alice, StatusKind::Stunned).unwrap();
+    assert_eq!(removed.len(), 1);
+    assert!(!world.is_stunned(alice));
+}
+
+#[test]
+fn test_stunned_creatures_lose_their_turn() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    let bob = spawn(&mut world, "Bob");
+    world
+        .start_encounter_with_initiative(&[(alice, 15), (bob, 10)])
+        .unwrap();
+    world
+        .apply_status(bob, StatusEffect::new(StatusKind::Stunned, 1))
+        .unwrap();
+
+    assert_eq!(world.end_turn().unwrap(), Some(alice));
+    let encounter = world.get_resource::<Encounter>().unwrap();
+    assert_eq!(encounter.get_round(), 2);
+    assert!(encounter.contains(bob));
+
+    // The stun wore off when the round ended.
+    assert_eq!(world.end_turn().unwrap(), Some(bob));
+}
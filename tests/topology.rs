@@ -0,0 +1,117 @@
+use simutron::creatures::morphologies::humanoid::humanoid_corpus;
+use simutron::prelude::*;
+
+fn at(map: &BaseMap<Forest>, x: u32, y: u32) -> Position {
+    Position {
+        map: Some(map.id),
+        x,
+        y,
+        z: 0,
+    }
+}
+
+fn walker(world: &mut World, speed: u32) -> Entity {
+    let walker = world.create_creature(Creature {
+        name: "Walker".to_string(),
+        corpus: humanoid_corpus(),
+    });
+    world.add_component(
+        walker,
+        CreatureSheet {
+            speed,
+            strength: 5,
+            intelligence: 5,
+            dexterity: 5,
+            constitution: 5,
+            wisdom: 5,
+            charisma: 5,
+        },
+    );
+    walker
+}
+
+#[test]
+fn test_hex_neighbours_and_distance() {
+    let odd_rows = GridTopology::Hex(HexCoordinates::OddRows);
+    // (1, 1) sits on an odd row, so it touches (2, 0) and (2, 2) but not (0, 0).
+    assert!(odd_rows.is_adjacent((1, 1), (2, 0)));
+    assert!(odd_rows.is_adjacent((1, 1), (2, 2)));
+    assert!(!odd_rows.is_adjacent((1, 1), (0, 0)));
+    assert_eq!(odd_rows.get_neighbours(1, 1).len(), 6);
+    assert_eq!(odd_rows.get_distance((0, 0), (3, 3)), 5);
+
+    let axial = GridTopology::Hex(HexCoordinates::Axial);
+    assert!(axial.is_adjacent((1, 1), (2, 0)));
+    assert!(!axial.is_adjacent((1, 1), (2, 2)));
+    assert_eq!(axial.get_distance((0, 0), (3, 3)), 6);
+}
+
+#[test]
+fn test_square_4_rejects_diagonal_steps() {
+    let mut world = World::new();
+    let mut field = ForestBuilder::new(3, 3, 5, Tile::new(ForestMaterial::Grass));
+    field.set_topology(GridTopology::Square4);
+    let field = field.build();
+    world.add_map(field.clone());
+    let walker = walker(&mut world, 30);
+    world.teleport_creature(walker, at(&field, 0, 0)).unwrap();
+
+    assert!(world.move_creature(walker, vec![at(&field, 1, 1)]).is_err());
+    world
+        .move_creature(walker, vec![at(&field, 1, 0), at(&field, 1, 1)])
+        .unwrap();
+    let position = world.get_component::<Position>(walker).unwrap();
+    assert_eq!((position.x, position.y), (1, 1));
+    assert_eq!(
+        world
+            .get_distance(at(&field, 0, 0), at(&field, 2, 2))
+            .unwrap(),
+        20
+    );
+}
+
+#[test]
+fn test_hex_movement_has_no_diagonals() {
+    let mut world = World::new();
+    let mut field = ForestBuilder::new(5, 5, 5, Tile::new(ForestMaterial::Grass));
+    field.set_topology(GridTopology::Hex(HexCoordinates::OddRows));
+    let field = field.build();
+    world.add_map(field.clone());
+    let walker = walker(&mut world, 10);
+    world.teleport_creature(walker, at(&field, 1, 1)).unwrap();
+
+    // Two hex steps cost exactly two tiles.
+    world
+        .move_creature(walker, vec![at(&field, 2, 2), at(&field, 2, 3)])
+        .unwrap();
+    let position = world.get_component::<Position>(walker).unwrap();
+    assert_eq!((position.x, position.y), (2, 3));
+    assert_eq!(
+        world
+            .get_distance(at(&field, 0, 0), at(&field, 3, 3))
+            .unwrap(),
+        25
+    );
+}
+
+#[test]
+fn test_find_path_avoids_blocking_terrain() {
+    let mut world = World::new();
+    let mut forest = ForestBuilder::new(5, 3, 5, Tile::new(ForestMaterial::Soil));
+    forest.add_base_material(2, 0, Tile::new(ForestMaterial::FallenTree));
+    forest.add_base_material(2, 1, Tile::new(ForestMaterial::FallenRocks));
+    let forest = forest.build();
+    world.add_map(forest.clone());
+
+    let path = world
+        .find_path(at(&forest, 0, 0), at(&forest, 4, 0))
+        .unwrap();
+    assert_eq!(path.last().map(|p| (p.x, p.y)), Some((4, 0)));
+    assert!(path.iter().all(|p| p.x != 2 || p.y == 2));
+
+    let walker = walker(&mut world, 100);
+    world.teleport_creature(walker, at(&forest, 0, 0)).unwrap();
+    world.move_creature(walker, path).unwrap();
+    let position = world.get_component::<Position>(walker).unwrap();
+    assert_eq!((position.x, position.y), (4, 0));
+}
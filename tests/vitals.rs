@@ -0,0 +1,132 @@
+mod common;
+
+use common::*;
+use simutron::prelude::*;
+
+fn vital_state(world: &World, creature: Entity) -> VitalState {
+    world
+        .get_component::<Creature>(creature)
+        .unwrap()
+        .get_vital_state()
+}
+
+#[test]
+fn test_vital_state_transitions() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    hit(
+        &mut world,
+        alice,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        -45,
+    );
+    assert_eq!(vital_state(&world, alice), VitalState::Alive);
+    assert!(world.drain_vital_events().is_empty());
+
+    hit(&mut world, alice, "Head", AppendageEffect::Abrasion, -45);
+    hit(&mut world, alice, "Head", AppendageEffect::Abrasion, -20);
+    assert_eq!(vital_state(&world, alice), VitalState::Dying);
+
+    // The dying bleed out on their own.
+    world.tick().unwrap();
+    assert_eq!(vital_state(&world, alice), VitalState::Dying);
+    world.tick().unwrap();
+    assert_eq!(vital_state(&world, alice), VitalState::Dead);
+
+    let events = world.drain_vital_events();
+    let changes: Vec<_> = events
+        .iter()
+        .map(|event| (event.previous, event.current))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (VitalState::Alive, VitalState::Unconscious),
+            (VitalState::Unconscious, VitalState::Dying),
+            (VitalState::Dying, VitalState::Dead),
+        ]
+    );
+    assert!(events.iter().all(|event| event.creature == alice));
+    assert!(world.drain_vital_events().is_empty());
+}
+
+#[test]
+fn test_helmets_do_not_stop_bleeding_out() {
+    let mut knight = MorphologyBuilder::new("Torso");
+    knight
+        .add_appendage("Torso", AppendageSpec::new("Head").with_armor(10))
+        .unwrap();
+    knight
+        .add_capabilities("Head", &[Capability::Vital])
+        .unwrap();
+    let mut world = World::new();
+    let knight = spawn_with(&mut world, "Knight", knight.build());
+    hit(&mut world, knight, "Head", AppendageEffect::Abrasion, -70);
+    assert_eq!(vital_state(&world, knight), VitalState::Dying);
+
+    // The bleed is far smaller than the armor, and kills all the same.
+    for _ in 0..20 {
+        world.tick().unwrap();
+    }
+    assert_eq!(vital_state(&world, knight), VitalState::Dead);
+}
+
+#[test]
+fn test_knocked_out_creatures_lose_their_turns() {
+    let mut world = World::new();
+    let alice = spawn(&mut world, "Alice");
+    let bob = spawn(&mut world, "Bob");
+    let carol = spawn(&mut world, "Carol");
+    world
+        .start_encounter_with_initiative(&[(alice, 15), (bob, 10), (carol, 5)])
+        .unwrap();
+
+    hit(&mut world, carol, "Head", AppendageEffect::Abrasion, -45);
+    hit(&mut world, alice, "Head", AppendageEffect::Abrasion, -45);
+    assert_eq!(vital_state(&world, alice), VitalState::Unconscious);
+    let encounter = world.get_resource::<Encounter>().unwrap();
+    assert!(encounter.contains(alice));
+    assert!(encounter.contains(carol));
+
+    // The unconscious keep their place but are skipped, round after round.
+    assert_eq!(world.end_turn().unwrap(), Some(bob));
+    assert_eq!(world.end_turn().unwrap(), Some(bob));
+    assert_eq!(world.get_resource::<Encounter>().unwrap().get_round(), 2);
+
+    // Once nobody can act, nobody gets a turn.
+    hit(&mut world, bob, "Head", AppendageEffect::Abrasion, -45);
+    assert_eq!(world.end_turn().unwrap(), None);
+    assert!(world.get_resource::<Encounter>().unwrap().contains(bob));
+}
+
+#[test]
+fn test_dead_creatures_leave_corpses() {
+    let mut world = World::new();
+    world.insert_resource(DeathRules {
+        leave_corpses: true,
+    });
+    let alice = spawn(&mut world, "Alice");
+    let jar = world.create_prop("Jar", "A clay jar.");
+    world.add_to_inventory(alice, jar.0).unwrap();
+
+    hit(&mut world, alice, "Head", AppendageEffect::Crush, -40);
+    assert!(world.get_component::<Creature>(alice).is_none());
+    assert!(world.get_creature_by_name("Alice").is_none());
+
+    let corpse = world.get_entities_with::<Corpse>()[0];
+    assert_eq!(
+        world.get_component::<Prop>(corpse).unwrap().name,
+        "Alice's corpse"
+    );
+    assert_eq!(
+        world.get_component::<Inventory>(corpse).unwrap().items,
+        vec![jar.0]
+    );
+    let remains = &world.get_component::<Corpse>(corpse).unwrap().creature;
+    assert!(remains.corpus.find("Head").is_some());
+
+    let events = world.drain_vital_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].current, VitalState::Dead);
+}
@@ -0,0 +1,227 @@
+mod common;
+
+use common::*;
+use simutron::prelude::*;
+use std::time::Duration;
+
+#[test]
+fn test_crush_disables_outright() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+
+    // 35 damage from a cut only wounds an arm, but the same from a club breaks it.
+    hit(
+        &mut world,
+        patient,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        -35,
+    );
+    hit(
+        &mut world,
+        patient,
+        "Right Arm",
+        AppendageEffect::Crush,
+        -35,
+    );
+    let left = appendage(&world, patient, "Left Arm");
+    let right = appendage(&world, patient, "Right Arm");
+    assert_eq!(left.get_health(), right.get_health());
+    assert_eq!(left.get_state(), AppendageHealth::Wounded);
+    assert_eq!(right.get_state(), AppendageHealth::Disabled);
+}
+
+#[test]
+fn test_burns_resist_healing() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    hit(
+        &mut world,
+        patient,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        -20,
+    );
+    hit(&mut world, patient, "Right Arm", AppendageEffect::Burn, -20);
+    hit(
+        &mut world,
+        patient,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        10,
+    );
+    hit(&mut world, patient, "Right Arm", AppendageEffect::Burn, 10);
+
+    assert_eq!(appendage(&world, patient, "Left Arm").get_health(), 90);
+    assert_eq!(appendage(&world, patient, "Right Arm").get_health(), 85);
+}
+
+#[test]
+fn test_effects_are_recorded_separately() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    hit(&mut world, patient, "Head", AppendageEffect::Abrasion, -5);
+    hit(&mut world, patient, "Head", AppendageEffect::Burn, -10);
+    hit(&mut world, patient, "Head", AppendageEffect::Crush, -15);
+
+    let head = appendage(&world, patient, "Head");
+    assert_eq!(head.get_effect_impact(AppendageEffect::Abrasion), -5);
+    assert_eq!(head.get_effect_impact(AppendageEffect::Burn), -10);
+    assert_eq!(head.get_effect_impact(AppendageEffect::Crush), -15);
+    assert_eq!(head.get_effect_impact(AppendageEffect::Poison), 0);
+    assert_eq!(head.get_health(), 70);
+}
+
+#[test]
+fn test_poison_spreads_to_the_torso() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    hit(
+        &mut world,
+        patient,
+        "Left Hand",
+        AppendageEffect::Poison,
+        -10,
+    );
+    hit(
+        &mut world,
+        patient,
+        "Right Foot",
+        AppendageEffect::Poison,
+        -10,
+    );
+
+    world.tick().unwrap();
+    world.tick().unwrap();
+    let torso = appendage(&world, patient, "Torso");
+    assert_eq!(torso.get_effect_impact(AppendageEffect::Poison), -8);
+    assert_eq!(appendage(&world, patient, "Left Hand").get_health(), 90);
+
+    // An antidote in one limb halves the spread.
+    hit(&mut world, patient, "Left Hand", AppendageEffect::Poison, 1);
+    world.tick().unwrap();
+    let torso = appendage(&world, patient, "Torso");
+    assert_eq!(torso.get_effect_impact(AppendageEffect::Poison), -10);
+}
+
+#[test]
+fn test_infection_worsens_until_treated() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    hit(&mut world, patient, "Left Leg", AppendageEffect::Infect, -5);
+
+    // The clock ticks once per six second turn.
+    let report = world.advance(Duration::from_secs(18)).unwrap();
+    assert_eq!(report.ticks, 3);
+    let leg = appendage(&world, patient, "Left Leg");
+    assert_eq!(leg.get_effect_impact(AppendageEffect::Infect), -11);
+    assert!(leg.is_untreated(AppendageEffect::Infect));
+
+    hit(&mut world, patient, "Left Leg", AppendageEffect::Infect, 3);
+    world.advance(Duration::from_secs(60)).unwrap();
+    let leg = appendage(&world, patient, "Left Leg");
+    assert_eq!(leg.get_effect_impact(AppendageEffect::Infect), -8);
+    assert!(!leg.is_untreated(AppendageEffect::Infect));
+}
+
+#[test]
+fn test_encounter_rounds_tick() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    let medic = spawn(&mut world, "Medic");
+    hit(&mut world, patient, "Left Leg", AppendageEffect::Infect, -5);
+    world
+        .start_encounter_with_initiative(&[(patient, 10), (medic, 5)])
+        .unwrap();
+
+    world.end_turn().unwrap();
+    let leg = appendage(&world, patient, "Left Leg");
+    assert_eq!(leg.get_effect_impact(AppendageEffect::Infect), -5);
+    world.end_turn().unwrap();
+    let leg = appendage(&world, patient, "Left Leg");
+    assert_eq!(leg.get_effect_impact(AppendageEffect::Infect), -7);
+    assert_eq!(world.get_clock().elapsed, Duration::from_secs(6));
+}
+
+#[test]
+fn test_amputation_cascades_to_children() {
+    let mut world = World::new();
+    let patient = spawn(&mut world, "Patient");
+    hit(
+        &mut world,
+        patient,
+        "Left Arm",
+        AppendageEffect::Abrasion,
+        -90,
+    );
+    hit(
+        &mut world,
+        patient,
+        "Right Leg",
+        AppendageEffect::Crush,
+        -40,
+    );
+
+    // The hand goes wherever the arm goes.
+    let arm = world.get_entities_with::<SeveredAppendage>()[0];
+    let arm = &world
+        .get_component::<SeveredAppendage>(arm)
+        .unwrap()
+        .appendage;
+    let hand = arm.find("Left Hand").unwrap();
+    assert_eq!(hand.get_health(), 100);
+    assert_eq!(hand.get_state(), AppendageHealth::Amputated);
+    assert_eq!(
+        appendage(&world, patient, "Right Foot").get_state(),
+        AppendageHealth::Disabled
+    );
+    assert_eq!(
+        appendage(&world, patient, "Torso").get_state(),
+        AppendageHealth::Full
+    );
+}
+
+#[test]
+fn test_cascade_rules_per_morphology() {
+    let mut hydra = MorphologyBuilder::new("Body");
+    hydra.add_appendage("Body", "Neck").unwrap();
+    hydra.add_appendage("Neck", "Head").unwrap();
+    hydra.add_appendage("Body", "Tail").unwrap();
+    hydra.set_cascade_rules(CascadeRules {
+        sever_children: false,
+        disable_children: false,
+        bubble_up_percent: 50,
+    });
+    let mut world = World::new();
+    let hydra = world.create_creature(Creature {
+        name: "Hydra".to_string(),
+        corpus: hydra.build(),
+    });
+
+    // Half of every blow reaches the next appendage up, but no further.
+    hit(&mut world, hydra, "Head", AppendageEffect::Abrasion, -20);
+    assert_eq!(appendage(&world, hydra, "Neck").get_health(), 90);
+    assert_eq!(appendage(&world, hydra, "Body").get_health(), 100);
+
+    // Losing the neck spares the head, but the head still goes with it.
+    hit(&mut world, hydra, "Neck", AppendageEffect::Abrasion, -90);
+    assert_eq!(appendage(&world, hydra, "Body").get_health(), 55);
+    let body = world.get_component::<Creature>(hydra).unwrap();
+    assert!(body.corpus.find("Neck").is_none());
+    assert!(body.corpus.find("Head").is_none());
+    let neck = world.get_entities_with::<SeveredAppendage>()[0];
+    let neck = &world
+        .get_component::<SeveredAppendage>(neck)
+        .unwrap()
+        .appendage;
+    assert_eq!(
+        neck.find("Head").unwrap().get_state(),
+        AppendageHealth::Wounded
+    );
+    hit(&mut world, hydra, "Tail", AppendageEffect::Abrasion, -10);
+    assert_eq!(appendage(&world, hydra, "Body").get_health(), 50);
+
+    // Healing stays where it is applied.
+    hit(&mut world, hydra, "Tail", AppendageEffect::Abrasion, 10);
+    assert_eq!(appendage(&world, hydra, "Body").get_health(), 50);
+}